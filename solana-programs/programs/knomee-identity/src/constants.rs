@@ -13,20 +13,103 @@ pub const VOUCH_SEED: &[u8] = b"vouch";
 /// Seed for linked identity PDAs
 pub const LINKED_IDENTITY_SEED: &[u8] = b"linked_identity";
 
+/// Seed for reverse lookup PDAs (linked address -> primary)
+pub const LINKED_ADDRESS_SEED: &[u8] = b"linked_address";
+
+/// Seed for registered platform PDAs
+pub const PLATFORM_SEED: &[u8] = b"platform";
+
+/// Seed for identity migration (revoked key) PDAs
+pub const MIGRATION_SEED: &[u8] = b"migration";
+
+/// Seed for guardian recovery config PDAs
+pub const RECOVERY_CONFIG_SEED: &[u8] = b"recovery_config";
+
+/// Seed for per-tier soul-bound identity token mints
+pub const TIER_MINT_SEED: &[u8] = b"tier_mint";
+
+/// Seed for the identity token mint/burn authority PDA
+pub const IDENTITY_TOKEN_AUTHORITY_SEED: &[u8] = b"identity_token_authority";
+
+/// Seed for the KNOW rewards pool PDA (also the KNOW mint authority)
+pub const REWARDS_POOL_SEED: &[u8] = b"rewards_pool";
+
+/// Seed for the rewards pool vault token account
+pub const REWARDS_VAULT_SEED: &[u8] = b"rewards_vault";
+
+/// Seed for the UBI pool PDA
+pub const UBI_POOL_SEED: &[u8] = b"ubi_pool";
+
+/// Seed for the UBI vault token account
+pub const UBI_VAULT_SEED: &[u8] = b"ubi_vault";
+
+/// Seed for UBI epoch PDAs
+pub const EPOCH_SEED: &[u8] = b"epoch";
+
+/// Seed for the stake escrow token account
+pub const STAKE_ESCROW_SEED: &[u8] = b"stake_escrow";
+
+/// Seed for the treasury record PDA
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+/// Seed for the treasury vault token account
+pub const TREASURY_VAULT_SEED: &[u8] = b"treasury_vault";
+
+/// Seed for per-user stake account PDAs
+pub const STAKE_ACCOUNT_SEED: &[u8] = b"stake_account";
+
+/// Seed for vote delegation PDAs
+pub const DELEGATION_SEED: &[u8] = b"delegation";
+
+/// Seed for claim jury PDAs
+pub const JURY_SEED: &[u8] = b"jury";
+
+/// Seed for claim evidence PDAs
+pub const EVIDENCE_SEED: &[u8] = b"evidence";
+
+/// Slots after claim creation whose hash seeds the jury draw
+pub const JURY_DRAW_DELAY_SLOTS: u64 = 10;
+
+/// Default jury selection rate (0 = open voting)
+pub const DEFAULT_JURY_SELECTION_BPS: u16 = 0;
+
 /// Default consensus thresholds (basis points, 10000 = 100%)
 pub const DEFAULT_LINK_THRESHOLD: u16 = 5100; // 51%
 pub const DEFAULT_PRIMARY_THRESHOLD: u16 = 6700; // 67%
 pub const DEFAULT_DUPLICATE_THRESHOLD: u16 = 8000; // 80%
+pub const DEFAULT_APPEAL_THRESHOLD: u16 = 8500; // 85%
+pub const DEFAULT_RECOVERY_THRESHOLD: u16 = 8000; // 80%
 
 /// Default stake multipliers
 pub const DEFAULT_PRIMARY_STAKE_MULTIPLIER: u8 = 3;
 pub const DEFAULT_DUPLICATE_STAKE_MULTIPLIER: u8 = 10;
+pub const DEFAULT_APPEAL_STAKE_MULTIPLIER: u8 = 10;
+pub const DEFAULT_RECOVERY_STAKE_MULTIPLIER: u8 = 5;
 
 /// Default slashing rates (basis points)
 pub const DEFAULT_LINK_SLASH_BPS: u16 = 1000; // 10%
 pub const DEFAULT_PRIMARY_SLASH_BPS: u16 = 3000; // 30%
 pub const DEFAULT_DUPLICATE_SLASH_BPS: u16 = 5000; // 50%
+pub const DEFAULT_APPEAL_SLASH_BPS: u16 = 5000; // 50%
 pub const DEFAULT_SYBIL_SLASH_BPS: u16 = 10000; // 100%
+pub const DEFAULT_ORACLE_BOND_SLASH_BPS: u16 = 1000; // 10% of bond per losing Sybil vote
+
+/// Default minimum bonded stake to hold Oracle tier (10,000 KNOW, assuming 9 decimals)
+pub const DEFAULT_MIN_ORACLE_BOND: u64 = 10_000_000_000_000;
+
+/// Default split of slashed stake (basis points, must sum to 10000)
+pub const DEFAULT_SLASH_WINNERS_BPS: u16 = 7000; // 70% to winning voters
+pub const DEFAULT_SLASH_TREASURY_BPS: u16 = 2000; // 20% to treasury
+pub const DEFAULT_SLASH_BURN_BPS: u16 = 1000; // 10% burned
+
+/// Default claim creation fee paid to the treasury (0 = disabled)
+pub const DEFAULT_CLAIM_FEE_LAMPORTS: u64 = 0;
+
+/// Default bounty paid from the treasury to whoever resolves a claim (0.001 KNOW)
+pub const DEFAULT_RESOLUTION_BOUNTY_LAMPORTS: u64 = 1_000_000;
+
+/// Default vouches a claim needs before resolving it early earns the bounty (expired claims always do)
+pub const DEFAULT_RESOLUTION_BOUNTY_MIN_VOTES: u32 = 3;
 
 /// Default voting weights
 pub const DEFAULT_PRIMARY_VOTE_WEIGHT: u64 = 1;
@@ -37,6 +120,9 @@ pub const SECONDS_PER_DAY: i64 = 86400;
 pub const DEFAULT_FAILED_CLAIM_COOLDOWN: i64 = 7 * SECONDS_PER_DAY; // 7 days
 pub const DEFAULT_DUPLICATE_FLAG_COOLDOWN: i64 = 30 * SECONDS_PER_DAY; // 30 days
 pub const DEFAULT_CLAIM_EXPIRY_DURATION: i64 = 30 * SECONDS_PER_DAY; // 30 days
+pub const DEFAULT_APPEAL_WINDOW: i64 = 7 * SECONDS_PER_DAY; // 7 days
+pub const DEFAULT_STAKE_UNBONDING_PERIOD: i64 = 7 * SECONDS_PER_DAY; // 7 days
+pub const MIN_UBI_EPOCH_DURATION: i64 = SECONDS_PER_DAY; // 1 day
 
 /// Oracle decay rates (basis points per day)
 pub const DEFAULT_ORACLE_DECAY_RATE_BPS: u16 = 10; // 0.1% per day
@@ -46,17 +132,57 @@ pub const DEFAULT_ADMIN_DECAY_RATE_BPS: u16 = 50; // 0.5% per day
 pub const EARLY_ADOPTER_MULTIPLIER: u8 = 2;
 pub const EARLY_ADOPTER_PERIOD: i64 = 180 * SECONDS_PER_DAY; // 180 days
 
+/// KNOW rewards (assuming 9 decimals, before the early adopter multiplier)
+pub const MAX_KNOW_SUPPLY: u64 = 1_000_000_000_000_000_000; // 1B KNOW
+pub const PRIMARY_ID_REWARD: u64 = 100_000_000_000; // 100 KNOW
+pub const ORACLE_REWARD_PER_CLAIM: u64 = 10_000_000_000; // 10 KNOW
+pub const DEFAULT_VOTING_REWARD: u64 = 1_000_000_000; // 1 KNOW per winning vouch
+
+/// Default stake cap for capped stake weighting (1,000 KNOW, assuming 9 decimals)
+pub const DEFAULT_STAKE_WEIGHT_CAP_LAMPORTS: u64 = 1_000_000_000_000;
+
+/// Default per-vouch limits (0 stake and 10000 bps mean unlimited)
+pub const DEFAULT_MAX_STAKE_PER_VOUCH: u64 = 0;
+pub const DEFAULT_MAX_WEIGHT_SHARE_BPS: u16 = 10000;
+
+/// Default accuracy reputation settings (10000 floor = accuracy does not affect weight)
+pub const DEFAULT_ACCURACY_FLOOR_BPS: u16 = 10000;
+pub const DEFAULT_MIN_VOTES_FOR_ACCURACY: u32 = 10;
+
 /// Basis points denominator
 pub const BASIS_POINTS: u16 = 10000;
 
 /// Maximum platform name length
 pub const MAX_PLATFORM_NAME_LEN: usize = 32;
 
+/// Default maximum linked identities per Primary
+pub const DEFAULT_MAX_LINKED_PER_PRIMARY: u16 = 20;
+
+/// Link indices per platform are `0..MAX_LINK_INDEX`, so all records can be enumerated
+pub const MAX_LINK_INDEX: u16 = 64;
+
+/// Maximum guardians per recovery config (approvals are tracked in a u16 bitmap)
+pub const MAX_GUARDIANS: usize = 10;
+
+/// Minimum owner veto window for guardian recovery
+pub const MIN_GUARDIAN_VETO_DELAY: i64 = SECONDS_PER_DAY; // 1 day
+
+/// Guardian proposals that do not reach the threshold in time can be replaced
+pub const GUARDIAN_PROPOSAL_EXPIRY: i64 = 7 * SECONDS_PER_DAY; // 7 days
+
 /// Maximum justification length
 pub const MAX_JUSTIFICATION_LEN: usize = 500;
 
 /// Maximum evidence length
 pub const MAX_EVIDENCE_LEN: usize = 1000;
 
+/// Limits for structured evidence entries
+pub const MAX_EVIDENCE_URI_LEN: usize = 200;
+pub const MAX_EVIDENCE_MEDIA_TYPE_LEN: usize = 64;
+pub const MAX_EVIDENCE_PER_CLAIM: u32 = 50;
+
+/// Maximum on-chain vouch rationale length
+pub const MAX_RATIONALE_LEN: usize = 280;
+
 /// Minimum KNOW stake (in lamports, assuming 9 decimals)
 pub const DEFAULT_MIN_STAKE_LAMPORTS: u64 = 10_000_000; // 0.01 KNOW (assuming 9 decimals)
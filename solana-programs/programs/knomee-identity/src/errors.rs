@@ -106,4 +106,247 @@ pub enum KnomeeError {
 
     #[msg("Invalid claim status")]
     InvalidClaimStatus,
+
+    #[msg("Stake weight cap must be greater than zero for capped weighting")]
+    InvalidStakeWeightCap,
+
+    #[msg("Stake exceeds the maximum allowed per vouch")]
+    StakeExceedsVouchLimit,
+
+    #[msg("Invalid weight share (must be between 1 and 10000 basis points)")]
+    InvalidWeightShare,
+
+    #[msg("Invalid accuracy floor (must be between 0 and 10000 basis points)")]
+    InvalidAccuracyFloor,
+
+    #[msg("Claim cannot be appealed (unresolved, expired, already appealed, or window closed)")]
+    ClaimNotAppealable,
+
+    #[msg("Only the claim's parties or eligible voters can appeal")]
+    NotEligibleToAppeal,
+
+    #[msg("The appealed claim account is required to resolve an appeal")]
+    AppealedClaimRequired,
+
+    #[msg("Only Oracles can vote on this claim")]
+    OracleOnlyClaim,
+
+    #[msg("Linked identity record does not match the linked address")]
+    LinkedAddressMismatch,
+
+    #[msg("Only the Primary or the linked account can change this link")]
+    UnauthorizedLinkChange,
+
+    #[msg("Invalid linked identity limit (must be greater than zero)")]
+    InvalidLinkedLimit,
+
+    #[msg("Platform name must be non-empty and use only a-z, 0-9, '-', '_' or '.'")]
+    InvalidPlatformName,
+
+    #[msg("Platform name is not in canonical form (trimmed, lowercase)")]
+    PlatformNotCanonical,
+
+    #[msg("Platform is not registered or has been disabled")]
+    PlatformNotRecognized,
+
+    #[msg("This key has been migrated to a new identity and is revoked")]
+    IdentityRevoked,
+
+    #[msg("Migration target must be a fresh GreyGhost identity")]
+    MigrationTargetNotBlank,
+
+    #[msg("Linked identities cannot be migrated; unlink instead")]
+    CannotMigrateLinkedIdentity,
+
+    #[msg("Cannot migrate an identity to its own key")]
+    CannotMigrateToSelf,
+
+    #[msg("Recovery claim is not approved, still appealable, or under appeal")]
+    RecoveryNotExecutable,
+
+    #[msg("Invalid guardian set (1 to 10 unique guardians, excluding the owner)")]
+    InvalidGuardianSet,
+
+    #[msg("Invalid guardian threshold (must be between 1 and the number of guardians)")]
+    InvalidGuardianThreshold,
+
+    #[msg("Guardian veto delay is below the minimum")]
+    GuardianVetoDelayTooShort,
+
+    #[msg("Signer is not a guardian of this identity")]
+    NotAGuardian,
+
+    #[msg("A recovery to a different new owner is already pending")]
+    RecoveryProposalMismatch,
+
+    #[msg("No recovery proposal is pending")]
+    NoPendingRecovery,
+
+    #[msg("Identity token mint or token account does not match the identity")]
+    IdentityTokenAccountMismatch,
+
+    #[msg("Identity token already matches the identity tier")]
+    IdentityTokenInSync,
+
+    #[msg("Rewards pool has insufficient KNOW")]
+    InsufficientRewardsPool,
+
+    #[msg("Funding would exceed maximum KNOW supply")]
+    MaxSupplyExceeded,
+
+    #[msg("Primary ID reward already claimed")]
+    PrimaryRewardAlreadyClaimed,
+
+    #[msg("No KNOW reward for this vouch")]
+    NoVotingReward,
+
+    #[msg("Token account is not for the KNOW mint")]
+    InvalidKnowMint,
+
+    #[msg("UBI per-capita amount must be greater than zero")]
+    InvalidUbiAmount,
+
+    #[msg("UBI epoch duration is too short")]
+    InvalidEpochDuration,
+
+    #[msg("Current UBI epoch has not ended")]
+    EpochStillActive,
+
+    #[msg("UBI epoch is not open for claims")]
+    EpochNotActive,
+
+    #[msg("UBI already claimed for this epoch")]
+    UbiAlreadyClaimed,
+
+    #[msg("UBI vault has insufficient KNOW")]
+    InsufficientUbiVault,
+
+    #[msg("Slash split must sum to 10000 basis points")]
+    InvalidSlashSplit,
+
+    #[msg("Treasury vault is required when a claim fee is set")]
+    TreasuryVaultRequired,
+
+    #[msg("Withdrawal amount must be greater than zero")]
+    InvalidWithdrawalAmount,
+
+    #[msg("Treasury has insufficient KNOW")]
+    InsufficientTreasury,
+
+    #[msg("Not all vouches on this claim have been settled")]
+    VouchesNotSettled,
+
+    #[msg("Not all vouch accounts on this claim have been closed")]
+    VouchesNotClosed,
+
+    #[msg("Claim can still be appealed or has a pending appeal")]
+    ClaimStillAppealable,
+
+    #[msg("Only the claim creator can perform this action")]
+    NotClaimCreator,
+
+    #[msg("Not enough unlocked KNOW in the stake account")]
+    InsufficientAvailableStake,
+
+    #[msg("No unbonded KNOW to withdraw")]
+    NothingToWithdraw,
+
+    #[msg("Unbonding period has not elapsed")]
+    StakeStillUnbonding,
+
+    #[msg("Unbonding period cannot be negative")]
+    InvalidUnbondingPeriod,
+
+    #[msg("Oracle bond is below the governance minimum")]
+    OracleBondTooLow,
+
+    #[msg("Oracle bond meets the governance minimum")]
+    OracleBondSufficient,
+
+    #[msg("Identity is not an Oracle")]
+    NotAnOracle,
+
+    #[msg("Identity is already delegating its vote")]
+    AlreadyDelegated,
+
+    #[msg("Cannot delegate to yourself")]
+    CannotDelegateToSelf,
+
+    #[msg("Voter has delegated its vote for this claim")]
+    VoterHasDelegated,
+
+    #[msg("Invalid delegation accounts")]
+    InvalidDelegationAccounts,
+
+    #[msg("Claim does not use jury selection")]
+    JuryNotRequired,
+
+    #[msg("Jury has not been drawn for this claim")]
+    JuryNotDrawn,
+
+    #[msg("Voter was not selected for this claim's jury")]
+    NotAJuror,
+
+    #[msg("Jury source slot hash is not available")]
+    JurySlotUnavailable,
+
+    #[msg("Invalid jury selection rate")]
+    InvalidJurySelection,
+
+    #[msg("Only the claim's subject, creator or voters can submit evidence")]
+    NotAClaimParticipant,
+
+    #[msg("Invalid evidence (empty hash or URI, or too long)")]
+    InvalidEvidence,
+
+    #[msg("Claim has reached the evidence limit")]
+    TooMuchEvidence,
+
+    #[msg("Not the evidence submitter")]
+    NotEvidenceSubmitter,
+
+    #[msg("All evidence must be closed first")]
+    EvidenceNotClosed,
+
+    #[msg("Vouch rationale already set")]
+    RationaleAlreadySet,
+
+    #[msg("Invalid rationale (empty or too long)")]
+    InvalidRationale,
+
+    #[msg("Claim outcome is not final: it can still be appealed or its appeal is pending")]
+    ClaimNotFinal,
+
+    #[msg("Appellant identity is required to resolve this appeal")]
+    AppellantIdentityRequired,
+
+    #[msg("Link index out of range")]
+    InvalidLinkIndex,
+
+    #[msg("Stake account still has vouches to settle")]
+    StakeHasActiveVouches,
+
+    #[msg("Oracle bond must be released first")]
+    StakeStillBonded,
+
+    #[msg("Related identity account required to resolve a duplicate challenge")]
+    RelatedIdentityRequired,
+
+    #[msg("Identity is the subject of an active or appealable claim")]
+    IdentityHasOpenClaims,
+
+    #[msg("Recovering key's stake account required to receive the Oracle bond")]
+    StakeAccountRequired,
+
+    #[msg("Claim the KNOW voting reward before closing this vouch")]
+    VotingRewardNotClaimed,
+
+    #[msg("Creator stake must be settled before closing the claim")]
+    CreatorStakeNotSettled,
+
+    #[msg("Jury has already been drawn for this claim")]
+    JuryAlreadyDrawn,
+
+    #[msg("Jury source slot is still available or not reached yet")]
+    JurySlotAvailable,
 }
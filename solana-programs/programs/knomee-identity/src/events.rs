@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when governance withdraws KNOW from the treasury
+#[event]
+pub struct TreasuryWithdrawal {
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub remaining_balance: u64,
+    pub withdrawal_index: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a voter attaches a rationale to their vouch
+#[event]
+pub struct VouchRationaleSet {
+    pub claim_id: u64,
+    pub voucher: Pubkey,
+    pub supports: bool,
+    pub stake: u64,
+    pub rationale: String,
+    pub rationale_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when evidence is submitted on a claim
+#[event]
+pub struct EvidenceSubmitted {
+    pub claim_id: u64,
+    pub index: u32,
+    pub submitter: Pubkey,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub media_type: String,
+    pub timestamp: i64,
+}
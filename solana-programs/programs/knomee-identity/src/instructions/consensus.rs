@@ -1,6 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::{constants::*, errors::KnomeeError, state::*};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::{
+    constants::*,
+    errors::KnomeeError,
+    events::VouchRationaleSet,
+    instructions::{
+        delegation::delegated_weight, identity_token::*,
+        treasury::collect_claim_fee,
+    },
+    state::*,
+};
 
 // ============================================================
 // REQUEST LINK TO PRIMARY
@@ -37,8 +46,19 @@ pub struct RequestLinkToPrimary<'info> {
     pub subject_identity: Account<'info, Identity>,
 
     /// Primary identity to link to
-    /// CHECK: Validated in handler
-    pub primary: UncheckedAccount<'info>,
+    #[account(
+        seeds = [IDENTITY_SEED, primary_address.as_ref()],
+        bump = primary_identity.bump,
+        constraint = primary_identity.is_primary() @ KnomeeError::NotAPrimaryId
+    )]
+    pub primary_identity: Account<'info, Identity>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, platform.as_bytes()],
+        bump = registered_platform.bump,
+        constraint = registered_platform.enabled @ KnomeeError::PlatformNotRecognized
+    )]
+    pub registered_platform: Account<'info, RegisteredPlatform>,
 
     #[account(mut)]
     pub subject: Signer<'info>,
@@ -48,9 +68,21 @@ pub struct RequestLinkToPrimary<'info> {
     pub subject_token_account: Account<'info, TokenAccount>,
 
     /// Escrow account to hold staked KNOW
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
     pub stake_escrow: Account<'info, TokenAccount>,
 
+    /// Treasury vault (required when governance sets a claim fee)
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -62,10 +94,7 @@ pub fn request_link_to_primary(
     justification: String,
     stake_amount: u64,
 ) -> Result<()> {
-    require!(
-        platform.len() <= MAX_PLATFORM_NAME_LEN,
-        KnomeeError::PlatformNameTooLong
-    );
+    require_canonical_platform(&platform)?;
     require!(
         justification.len() <= MAX_JUSTIFICATION_LEN,
         KnomeeError::JustificationTooLong
@@ -95,6 +124,12 @@ pub fn request_link_to_primary(
         KnomeeError::AddressUnderChallenge
     );
 
+    // Primary must have room for another linked identity
+    require!(
+        ctx.accounts.primary_identity.linked_count < governance.params.max_linked_per_primary,
+        KnomeeError::MaxLinkedIdentitiesReached
+    );
+
     // Transfer stake to escrow
     let transfer_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -106,12 +141,26 @@ pub fn request_link_to_primary(
     );
     token::transfer(transfer_ctx, stake_amount)?;
 
+    collect_claim_fee(
+        governance.params.claim_fee_lamports,
+        &ctx.accounts.subject_token_account,
+        &ctx.accounts.subject,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Track the open claim on its subject (blocks migration until it settles)
+    subject_identity
+        .claim_opened()
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
     // Initialize claim
     let claim_id = next_claim_id();
     claim.claim_id = claim_id;
     claim.claim_type = ClaimType::LinkToPrimary;
     claim.status = ClaimStatus::Active;
     claim.subject = ctx.accounts.subject.key();
+    claim.creator = ctx.accounts.subject.key();
     claim.related_address = primary_address;
     claim.platform = platform;
     claim.justification = justification;
@@ -120,9 +169,26 @@ pub fn request_link_to_primary(
     claim.total_votes_for = 0;
     claim.total_votes_against = 0;
     claim.total_stake = stake_amount;
+    claim.creator_stake = stake_amount;
+    claim.creator_stake_settled = false;
     claim.total_slashed = 0;
+    claim.stake_for = 0;
+    claim.stake_against = 0;
     claim.vouch_count = 0;
+    claim.settled_vouch_count = 0;
+    claim.closed_vouch_count = 0;
     claim.rewards_distributed = false;
+    claim.stake_weighting = governance.params.stake_weighting;
+    claim.stake_weight_cap = governance.params.stake_weight_cap;
+    claim.resolved_at = 0;
+    claim.slash_terms = SlashTerms::default();
+    claim.appeal_of = 0;
+    claim.appeal_claim_id = 0;
+    claim.appeal_resolved = false;
+    claim.threshold_bps = 0;
+    claim.oracle_only = false;
+    claim.created_slot = Clock::get()?.slot;
+    claim.jury_selection_bps = governance.params.jury_selection_bps;
     claim.bump = ctx.bumps.claim;
 
     msg!("LinkToPrimary claim created: {}", claim_id);
@@ -173,9 +239,21 @@ pub struct RequestPrimaryVerification<'info> {
     #[account(mut)]
     pub subject_token_account: Account<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
     pub stake_escrow: Account<'info, TokenAccount>,
 
+    /// Treasury vault (required when governance sets a claim fee)
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -225,12 +303,26 @@ pub fn request_primary_verification(
     );
     token::transfer(transfer_ctx, stake_amount)?;
 
+    collect_claim_fee(
+        governance.params.claim_fee_lamports,
+        &ctx.accounts.subject_token_account,
+        &ctx.accounts.subject,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Track the open claim on its subject (blocks migration until it settles)
+    subject_identity
+        .claim_opened()
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
     // Initialize claim
     let claim_id = next_claim_id();
     claim.claim_id = claim_id;
     claim.claim_type = ClaimType::NewPrimary;
     claim.status = ClaimStatus::Active;
     claim.subject = ctx.accounts.subject.key();
+    claim.creator = ctx.accounts.subject.key();
     claim.related_address = Pubkey::default();
     claim.platform = String::new();
     claim.justification = justification;
@@ -239,9 +331,26 @@ pub fn request_primary_verification(
     claim.total_votes_for = 0;
     claim.total_votes_against = 0;
     claim.total_stake = stake_amount;
+    claim.creator_stake = stake_amount;
+    claim.creator_stake_settled = false;
     claim.total_slashed = 0;
+    claim.stake_for = 0;
+    claim.stake_against = 0;
     claim.vouch_count = 0;
+    claim.settled_vouch_count = 0;
+    claim.closed_vouch_count = 0;
     claim.rewards_distributed = false;
+    claim.stake_weighting = governance.params.stake_weighting;
+    claim.stake_weight_cap = governance.params.stake_weight_cap;
+    claim.resolved_at = 0;
+    claim.slash_terms = SlashTerms::default();
+    claim.appeal_of = 0;
+    claim.appeal_claim_id = 0;
+    claim.appeal_resolved = false;
+    claim.threshold_bps = 0;
+    claim.oracle_only = false;
+    claim.created_slot = Clock::get()?.slot;
+    claim.jury_selection_bps = governance.params.jury_selection_bps;
     claim.bump = ctx.bumps.claim;
 
     msg!("NewPrimary claim created: {}", claim_id);
@@ -292,9 +401,21 @@ pub struct ChallengeDuplicate<'info> {
     #[account(mut)]
     pub challenger_token_account: Account<'info, TokenAccount>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
     pub stake_escrow: Account<'info, TokenAccount>,
 
+    /// Treasury vault (required when governance sets a claim fee)
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -341,6 +462,19 @@ pub fn challenge_duplicate(
     );
     token::transfer(transfer_ctx, stake_amount)?;
 
+    collect_claim_fee(
+        governance.params.claim_fee_lamports,
+        &ctx.accounts.challenger_token_account,
+        &ctx.accounts.challenger,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Track the open claim on its subject (blocks migration until it settles)
+    identity1
+        .claim_opened()
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
     // Mark both identities as under challenge
     let claim_id = next_claim_id();
     identity1.under_challenge = true;
@@ -353,6 +487,7 @@ pub fn challenge_duplicate(
     claim.claim_type = ClaimType::DuplicateFlag;
     claim.status = ClaimStatus::Active;
     claim.subject = addr1;
+    claim.creator = ctx.accounts.challenger.key();
     claim.related_address = addr2;
     claim.platform = String::new();
     claim.justification = evidence;
@@ -361,9 +496,26 @@ pub fn challenge_duplicate(
     claim.total_votes_for = 0;
     claim.total_votes_against = 0;
     claim.total_stake = stake_amount;
+    claim.creator_stake = stake_amount;
+    claim.creator_stake_settled = false;
     claim.total_slashed = 0;
+    claim.stake_for = 0;
+    claim.stake_against = 0;
     claim.vouch_count = 0;
+    claim.settled_vouch_count = 0;
+    claim.closed_vouch_count = 0;
     claim.rewards_distributed = false;
+    claim.stake_weighting = governance.params.stake_weighting;
+    claim.stake_weight_cap = governance.params.stake_weight_cap;
+    claim.resolved_at = 0;
+    claim.slash_terms = SlashTerms::default();
+    claim.appeal_of = 0;
+    claim.appeal_claim_id = 0;
+    claim.appeal_resolved = false;
+    claim.threshold_bps = 0;
+    claim.oracle_only = false;
+    claim.created_slot = Clock::get()?.slot;
+    claim.jury_selection_bps = governance.params.jury_selection_bps;
     claim.bump = ctx.bumps.claim;
 
     msg!("DuplicateFlag claim created: {}", claim_id);
@@ -374,6 +526,178 @@ pub fn challenge_duplicate(
     Ok(())
 }
 
+// ============================================================
+// REQUEST RECOVERY
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(lost_address: Pubkey, justification: String, stake_amount: u64)]
+pub struct RequestRecovery<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = subject,
+        space = IdentityClaim::space(0, justification.len()),
+        seeds = [CLAIM_SEED, &governance.initialized_at.to_le_bytes(), &next_claim_id().to_le_bytes()],
+        bump
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    /// New key's identity (must be a fresh GreyGhost)
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, subject.key().as_ref()],
+        bump = subject_identity.bump,
+        constraint = subject_identity.is_blank() @ KnomeeError::MigrationTargetNotBlank
+    )]
+    pub subject_identity: Account<'info, Identity>,
+
+    /// Identity whose key was lost
+    #[account(
+        seeds = [IDENTITY_SEED, lost_address.as_ref()],
+        bump = lost_identity.bump,
+        constraint = lost_identity.is_primary() @ KnomeeError::NotAPrimaryId
+    )]
+    pub lost_identity: Account<'info, Identity>,
+
+    #[account(mut)]
+    pub subject: Signer<'info>,
+
+    #[account(mut)]
+    pub subject_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    /// Treasury vault (required when governance sets a claim fee)
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn request_recovery(
+    ctx: Context<RequestRecovery>,
+    lost_address: Pubkey,
+    justification: String,
+    stake_amount: u64,
+) -> Result<()> {
+    require!(
+        justification.len() <= MAX_JUSTIFICATION_LEN,
+        KnomeeError::JustificationTooLong
+    );
+    require!(
+        lost_address != ctx.accounts.subject.key(),
+        KnomeeError::CannotMigrateToSelf
+    );
+
+    let governance = &ctx.accounts.governance;
+    let claim = &mut ctx.accounts.claim;
+    let subject_identity = &mut ctx.accounts.subject_identity;
+    let current_time = governance.current_time();
+
+    // Check cooldown
+    if subject_identity.last_failed_claim_at > 0 {
+        let cooldown = ClaimType::Recovery.cooldown_period(&governance.params);
+        require!(
+            current_time >= subject_identity.last_failed_claim_at + cooldown,
+            KnomeeError::CooldownNotElapsed
+        );
+    }
+
+    // Cannot recover an identity to escape a duplicate challenge
+    require!(
+        !ctx.accounts.lost_identity.under_challenge,
+        KnomeeError::AddressUnderChallenge
+    );
+
+    // Validate stake (recovery multiplier applies)
+    let min_stake = governance.params.min_stake_lamports
+        * ClaimType::Recovery.required_stake_multiplier(&governance.params);
+    require!(stake_amount >= min_stake, KnomeeError::InsufficientStake);
+
+    // Transfer stake
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.subject_token_account.to_account_info(),
+            to: ctx.accounts.stake_escrow.to_account_info(),
+            authority: ctx.accounts.subject.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, stake_amount)?;
+
+    collect_claim_fee(
+        governance.params.claim_fee_lamports,
+        &ctx.accounts.subject_token_account,
+        &ctx.accounts.subject,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Track the open claim on its subject (blocks migration until it settles)
+    subject_identity
+        .claim_opened()
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    // Initialize claim
+    let claim_id = next_claim_id();
+    claim.claim_id = claim_id;
+    claim.claim_type = ClaimType::Recovery;
+    claim.status = ClaimStatus::Active;
+    claim.subject = ctx.accounts.subject.key();
+    claim.creator = ctx.accounts.subject.key();
+    claim.related_address = lost_address;
+    claim.platform = String::new();
+    claim.justification = justification;
+    claim.created_at = current_time;
+    claim.expires_at = current_time + governance.params.claim_expiry_duration;
+    claim.total_votes_for = 0;
+    claim.total_votes_against = 0;
+    claim.total_stake = stake_amount;
+    claim.creator_stake = stake_amount;
+    claim.creator_stake_settled = false;
+    claim.total_slashed = 0;
+    claim.stake_for = 0;
+    claim.stake_against = 0;
+    claim.vouch_count = 0;
+    claim.settled_vouch_count = 0;
+    claim.closed_vouch_count = 0;
+    claim.rewards_distributed = false;
+    claim.stake_weighting = governance.params.stake_weighting;
+    claim.stake_weight_cap = governance.params.stake_weight_cap;
+    claim.resolved_at = 0;
+    claim.slash_terms = SlashTerms::default();
+    claim.appeal_of = 0;
+    claim.appeal_claim_id = 0;
+    claim.appeal_resolved = false;
+    claim.threshold_bps = 0;
+    claim.oracle_only = false;
+    claim.created_slot = Clock::get()?.slot;
+    claim.jury_selection_bps = governance.params.jury_selection_bps;
+    claim.bump = ctx.bumps.claim;
+
+    msg!("Recovery claim created: {}", claim_id);
+    msg!("New key: {}", claim.subject);
+    msg!("Lost key: {}", lost_address);
+
+    Ok(())
+}
+
 // ============================================================
 // VOUCH FOR
 // ============================================================
@@ -401,36 +725,52 @@ pub struct VouchFor<'info> {
     )]
     pub voucher_identity: Account<'info, Identity>,
 
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, claim.subject.as_ref()],
+        bump = subject_identity.bump
+    )]
+    pub subject_identity: Account<'info, Identity>,
+
     #[account(
         init,
         payer = voucher,
-        space = Vouch::LEN,
+        space = Vouch::space(0),
         seeds = [VOUCH_SEED, &claim_id.to_le_bytes(), voucher.key().as_ref()],
         bump
     )]
     pub vouch: Account<'info, Vouch>,
 
-    #[account(mut)]
-    pub voucher: Signer<'info>,
+    /// Jury for this claim (required when the claim uses jury selection)
+    #[account(
+        seeds = [JURY_SEED, &claim_id.to_le_bytes()],
+        bump = jury.bump
+    )]
+    pub jury: Option<Account<'info, Jury>>,
 
-    #[account(mut)]
-    pub voucher_token_account: Account<'info, TokenAccount>,
+    /// Voucher's deposited KNOW, from which the vouch stake is locked
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, voucher.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
 
     #[account(mut)]
-    pub stake_escrow: Account<'info, TokenAccount>,
+    pub voucher: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn vouch_for(
-    ctx: Context<VouchFor>,
+pub fn vouch_for<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VouchFor<'info>>,
     claim_id: u64,
     stake_amount: u64,
 ) -> Result<()> {
     let governance = &ctx.accounts.governance;
     let claim = &mut ctx.accounts.claim;
     let voucher_identity = &ctx.accounts.voucher_identity;
+    let subject_identity = &mut ctx.accounts.subject_identity;
     let vouch = &mut ctx.accounts.vouch;
     let current_time = governance.current_time();
 
@@ -440,39 +780,92 @@ pub fn vouch_for(
         KnomeeError::ClaimExpired
     );
 
+    // Appeals may be restricted to Oracle voters
+    require!(
+        !claim.oracle_only || voucher_identity.is_oracle(),
+        KnomeeError::OracleOnlyClaim
+    );
+
+    // An under-bonded Oracle cannot vote with Oracle weight until it tops up its bond
+    require!(
+        !voucher_identity.is_oracle()
+            || ctx.accounts.stake_account.bonded >= governance.params.min_oracle_bond,
+        KnomeeError::OracleBondTooLow
+    );
+
+    // Delegators vote through their delegate, including on claims opened while delegated
+    require!(
+        voucher_identity.delegated_to == Pubkey::default()
+            && claim.created_at > voucher_identity.delegation_ended_at,
+        KnomeeError::VoterHasDelegated
+    );
+
+    // Jury claims only accept votes from drawn jurors
+    if claim.jury_selection_bps > 0 {
+        let jury = ctx.accounts.jury.as_ref().ok_or(KnomeeError::JuryNotDrawn)?;
+        require!(
+            jury.is_juror(&ctx.accounts.voucher.key()),
+            KnomeeError::NotAJuror
+        );
+    }
+
     // Validate minimum stake
     require!(
         stake_amount >= governance.params.min_stake_lamports,
         KnomeeError::InsufficientStake
     );
 
-    // Transfer stake
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.voucher_token_account.to_account_info(),
-            to: ctx.accounts.stake_escrow.to_account_info(),
-            authority: ctx.accounts.voucher.to_account_info(),
-        },
+    // Validate per-vouch stake cap
+    let max_stake = governance.params.max_stake_per_vouch;
+    require!(
+        max_stake == 0 || stake_amount <= max_stake,
+        KnomeeError::StakeExceedsVouchLimit
     );
-    token::transfer(transfer_ctx, stake_amount)?;
 
-    // Calculate voting weight
-    let weight = voucher_identity.voting_weight(&governance.params);
+    // Calculate voting weight, plus any weight delegated to this voucher
+    let delegated = delegated_weight(
+        claim,
+        ctx.accounts.jury.as_deref().filter(|_| claim.jury_selection_bps > 0),
+        &ctx.accounts.voucher.key(),
+        &governance.params,
+        ctx.remaining_accounts,
+    )?;
+    let weight = voucher_identity
+        .voting_weight(&governance.params)
+        .checked_add(delegated)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    // Clamp the stake so the vote stays within the maximum share of total claim weight
+    let requested_stake = stake_amount;
+    let stake_amount = claim.capped_stake(weight, stake_amount, &governance.params);
+    if stake_amount < requested_stake {
+        msg!("Stake clamped from {} to {} by the weight share cap", requested_stake, stake_amount);
+    }
+
+    // Lock stake from the voucher's deposited balance
+    ctx.accounts
+        .stake_account
+        .lock(stake_amount)
+        .ok_or(KnomeeError::InsufficientAvailableStake)?;
 
     // Initialize vouch
     vouch.claim_id = claim_id;
     vouch.voucher = ctx.accounts.voucher.key();
     vouch.supports = true;
     vouch.weight = weight;
+    vouch.delegated_weight = delegated;
     vouch.stake = stake_amount;
     vouch.vouched_at = current_time;
     vouch.rewards_claimed = false;
     vouch.reward_amount = 0;
     vouch.bump = ctx.bumps.vouch;
 
+    let weighted_vote = vouch.weighted_vote(claim);
+    vouch.counted_vote = weighted_vote;
+    vouch.know_reward_paid = false;
+    vouch.voted_as_oracle = voucher_identity.is_oracle();
+
     // Update claim totals
-    let weighted_vote = vouch.weighted_vote();
     claim.total_votes_for = claim
         .total_votes_for
         .checked_add(weighted_vote)
@@ -481,11 +874,27 @@ pub fn vouch_for(
         .total_stake
         .checked_add(stake_amount)
         .ok_or(KnomeeError::ArithmeticOverflow)?;
+    claim.stake_for = claim
+        .stake_for
+        .checked_add(stake_amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
     claim.vouch_count = claim
         .vouch_count
         .checked_add(1)
         .ok_or(KnomeeError::ArithmeticOverflow)?;
 
+    // Record social backing on the subject (FOR on a duplicate flag or appeal backs the challenger)
+    if matches!(claim.claim_type, ClaimType::LinkToPrimary | ClaimType::NewPrimary) {
+        subject_identity.total_vouches_received = subject_identity
+            .total_vouches_received
+            .checked_add(1)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+        subject_identity.total_stake_received = subject_identity
+            .total_stake_received
+            .checked_add(stake_amount)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+    }
+
     msg!("Vouch FOR cast on claim {}", claim_id);
     msg!("Voucher: {}", vouch.voucher);
     msg!("Weight: {}, Stake: {}", weight, stake_amount);
@@ -523,27 +932,35 @@ pub struct VouchAgainst<'info> {
     #[account(
         init,
         payer = voucher,
-        space = Vouch::LEN,
+        space = Vouch::space(0),
         seeds = [VOUCH_SEED, &claim_id.to_le_bytes(), voucher.key().as_ref()],
         bump
     )]
     pub vouch: Account<'info, Vouch>,
 
-    #[account(mut)]
-    pub voucher: Signer<'info>,
+    /// Jury for this claim (required when the claim uses jury selection)
+    #[account(
+        seeds = [JURY_SEED, &claim_id.to_le_bytes()],
+        bump = jury.bump
+    )]
+    pub jury: Option<Account<'info, Jury>>,
 
-    #[account(mut)]
-    pub voucher_token_account: Account<'info, TokenAccount>,
+    /// Voucher's deposited KNOW, from which the vouch stake is locked
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, voucher.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
 
     #[account(mut)]
-    pub stake_escrow: Account<'info, TokenAccount>,
+    pub voucher: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn vouch_against(
-    ctx: Context<VouchAgainst>,
+pub fn vouch_against<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VouchAgainst<'info>>,
     claim_id: u64,
     stake_amount: u64,
 ) -> Result<()> {
@@ -558,35 +975,86 @@ pub fn vouch_against(
         KnomeeError::ClaimExpired
     );
 
+    require!(
+        !claim.oracle_only || voucher_identity.is_oracle(),
+        KnomeeError::OracleOnlyClaim
+    );
+
+    require!(
+        !voucher_identity.is_oracle()
+            || ctx.accounts.stake_account.bonded >= governance.params.min_oracle_bond,
+        KnomeeError::OracleBondTooLow
+    );
+
+    // Delegators vote through their delegate, including on claims opened while delegated
+    require!(
+        voucher_identity.delegated_to == Pubkey::default()
+            && claim.created_at > voucher_identity.delegation_ended_at,
+        KnomeeError::VoterHasDelegated
+    );
+
+    // Jury claims only accept votes from drawn jurors
+    if claim.jury_selection_bps > 0 {
+        let jury = ctx.accounts.jury.as_ref().ok_or(KnomeeError::JuryNotDrawn)?;
+        require!(
+            jury.is_juror(&ctx.accounts.voucher.key()),
+            KnomeeError::NotAJuror
+        );
+    }
+
     require!(
         stake_amount >= governance.params.min_stake_lamports,
         KnomeeError::InsufficientStake
     );
 
-    // Transfer stake
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.voucher_token_account.to_account_info(),
-            to: ctx.accounts.stake_escrow.to_account_info(),
-            authority: ctx.accounts.voucher.to_account_info(),
-        },
+    let max_stake = governance.params.max_stake_per_vouch;
+    require!(
+        max_stake == 0 || stake_amount <= max_stake,
+        KnomeeError::StakeExceedsVouchLimit
     );
-    token::transfer(transfer_ctx, stake_amount)?;
 
-    let weight = voucher_identity.voting_weight(&governance.params);
+    // Calculate voting weight, plus any weight delegated to this voucher
+    let delegated = delegated_weight(
+        claim,
+        ctx.accounts.jury.as_deref().filter(|_| claim.jury_selection_bps > 0),
+        &ctx.accounts.voucher.key(),
+        &governance.params,
+        ctx.remaining_accounts,
+    )?;
+    let weight = voucher_identity
+        .voting_weight(&governance.params)
+        .checked_add(delegated)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    // Clamp the stake so the vote stays within the maximum share of total claim weight
+    let requested_stake = stake_amount;
+    let stake_amount = claim.capped_stake(weight, stake_amount, &governance.params);
+    if stake_amount < requested_stake {
+        msg!("Stake clamped from {} to {} by the weight share cap", requested_stake, stake_amount);
+    }
+
+    // Lock stake from the voucher's deposited balance
+    ctx.accounts
+        .stake_account
+        .lock(stake_amount)
+        .ok_or(KnomeeError::InsufficientAvailableStake)?;
 
     vouch.claim_id = claim_id;
     vouch.voucher = ctx.accounts.voucher.key();
     vouch.supports = false;
     vouch.weight = weight;
+    vouch.delegated_weight = delegated;
     vouch.stake = stake_amount;
     vouch.vouched_at = current_time;
     vouch.rewards_claimed = false;
     vouch.reward_amount = 0;
     vouch.bump = ctx.bumps.vouch;
 
-    let weighted_vote = vouch.weighted_vote();
+    let weighted_vote = vouch.weighted_vote(claim);
+    vouch.counted_vote = weighted_vote;
+    vouch.know_reward_paid = false;
+    vouch.voted_as_oracle = voucher_identity.is_oracle();
+
     claim.total_votes_against = claim
         .total_votes_against
         .checked_add(weighted_vote)
@@ -595,6 +1063,10 @@ pub fn vouch_against(
         .total_stake
         .checked_add(stake_amount)
         .ok_or(KnomeeError::ArithmeticOverflow)?;
+    claim.stake_against = claim
+        .stake_against
+        .checked_add(stake_amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
     claim.vouch_count = claim
         .vouch_count
         .checked_add(1)
@@ -607,12 +1079,12 @@ pub fn vouch_against(
 }
 
 // ============================================================
-// RESOLVE CONSENSUS
+// VOUCH RATIONALE
 // ============================================================
 
 #[derive(Accounts)]
-#[instruction(claim_id: u64)]
-pub struct ResolveConsensus<'info> {
+#[instruction(claim_id: u64, rationale: String)]
+pub struct SetVouchRationale<'info> {
     #[account(
         seeds = [GOVERNANCE_SEED],
         bump = governance.bump
@@ -620,17 +1092,137 @@ pub struct ResolveConsensus<'info> {
     pub governance: Account<'info, Governance>,
 
     #[account(
-        mut,
-        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.status == ClaimStatus::Active @ KnomeeError::ClaimAlreadyResolved
     )]
     pub claim: Account<'info, IdentityClaim>,
 
     #[account(
         mut,
-        seeds = [IDENTITY_SEED, claim.subject.as_ref()],
-        bump = subject_identity.bump
+        seeds = [VOUCH_SEED, &claim_id.to_le_bytes(), voucher.key().as_ref()],
+        bump = vouch.bump,
+        realloc = Vouch::space(rationale.len()),
+        realloc::payer = voucher,
+        realloc::zero = false
+    )]
+    pub vouch: Account<'info, Vouch>,
+
+    #[account(mut)]
+    pub voucher: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Attach a rationale (short text and/or off-chain document hash) to a vouch.
+/// Set once while the claim is open, so the reasoning cannot be rewritten after the outcome.
+pub fn set_vouch_rationale(
+    ctx: Context<SetVouchRationale>,
+    claim_id: u64,
+    rationale: String,
+    rationale_hash: [u8; 32],
+) -> Result<()> {
+    let claim = &ctx.accounts.claim;
+    let vouch = &mut ctx.accounts.vouch;
+    let current_time = ctx.accounts.governance.current_time();
+
+    require!(
+        current_time < claim.expires_at,
+        KnomeeError::ClaimExpired
+    );
+    require!(!vouch.has_rationale(), KnomeeError::RationaleAlreadySet);
+    require!(
+        rationale.len() <= MAX_RATIONALE_LEN,
+        KnomeeError::InvalidRationale
+    );
+    require!(
+        !rationale.is_empty() || rationale_hash != [0u8; 32],
+        KnomeeError::InvalidRationale
+    );
+
+    vouch.rationale = rationale;
+    vouch.rationale_hash = rationale_hash;
+
+    emit!(VouchRationaleSet {
+        claim_id,
+        voucher: vouch.voucher,
+        supports: vouch.supports,
+        stake: vouch.stake,
+        rationale: vouch.rationale.clone(),
+        rationale_hash,
+        timestamp: current_time,
+    });
+
+    msg!("Rationale set for vouch on claim {} by {}", claim_id, vouch.voucher);
+
+    Ok(())
+}
+
+// ============================================================
+// RESOLVE CONSENSUS
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct ResolveConsensus<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, claim.subject.as_ref()],
+        bump = subject_identity.bump
     )]
     pub subject_identity: Account<'info, Identity>,
+
+    /// Second flagged address's identity (required when resolving a DuplicateFlag)
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, claim.related_address.as_ref()],
+        bump = related_identity.bump
+    )]
+    pub related_identity: Option<Account<'info, Identity>>,
+
+    /// Original claim (required when resolving an Appeal)
+    #[account(
+        mut,
+        constraint = appealed_claim.claim_id == claim.appeal_of @ KnomeeError::InvalidClaimStatus
+    )]
+    pub appealed_claim: Option<Account<'info, IdentityClaim>>,
+
+    /// Appellant's identity (required to resolve an Appeal filed by someone other than the subject)
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, claim.creator.as_ref()],
+        bump = creator_identity.bump,
+        constraint = claim.creator != claim.subject @ KnomeeError::InvalidClaimStatus
+    )]
+    pub creator_identity: Option<Account<'info, Identity>>,
+
+    /// Treasury vault paying the resolution bounty (omit to skip the bounty)
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Resolver's KNOW account receiving the bounty
+    #[account(mut)]
+    pub resolver_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Subject's tier token, burned or minted when the outcome changes its tier
+    pub tier_token: TierTokenAccounts<'info>,
 }
 
 pub fn resolve_consensus(
@@ -647,59 +1239,378 @@ pub fn resolve_consensus(
         KnomeeError::ClaimAlreadyResolved
     );
 
-    // Check if expired
+    claim.resolved_at = current_time;
+
     if current_time >= claim.expires_at {
+        // Expired without consensus
         claim.status = ClaimStatus::Expired;
-        subject_identity.last_failed_claim_at = current_time;
         msg!("Claim {} expired without consensus", claim_id);
-        return Ok(());
+    } else {
+        // Check consensus
+        let consensus_result = claim.consensus_reached(&governance.params);
+
+        match consensus_result {
+            Some(true) => {
+                // APPROVED
+                claim.status = ClaimStatus::Approved;
+
+                match claim.claim_type {
+                    ClaimType::LinkToPrimary => {
+                        // LinkToPrimary approved - identity linking will happen in separate instruction
+                        msg!("LinkToPrimary claim approved");
+                    }
+                    ClaimType::NewPrimary => {
+                        // Upgrade to Primary ID
+                        subject_identity.tier = IdentityTier::PrimaryID;
+                        subject_identity.verified_at = current_time;
+                        msg!("NewPrimary claim approved - identity upgraded to PrimaryID");
+                    }
+                    ClaimType::DuplicateFlag => {
+                        // Both addresses downgraded to GreyGhost
+                        subject_identity.tier = IdentityTier::GreyGhost;
+                        subject_identity.verified_at = 0;
+                        msg!("DuplicateFlag claim approved - Sybil detected");
+                    }
+                    ClaimType::Recovery => {
+                        // Recovery approved - migration happens in execute_recovery after the appeal window
+                        msg!("Recovery claim approved");
+                    }
+                    ClaimType::Appeal => {
+                        // Overturn the original claim's outcome and tier effects
+                        let appealed_claim = ctx
+                            .accounts
+                            .appealed_claim
+                            .as_mut()
+                            .ok_or(KnomeeError::AppealedClaimRequired)?;
+                        overturn_claim(appealed_claim, subject_identity, current_time);
+                        appealed_claim.snapshot_slash_terms(&governance.params);
+                        msg!("Appeal approved - claim {} overturned", appealed_claim.claim_id);
+                    }
+                }
+            }
+            Some(false) => {
+                // REJECTED
+                claim.status = ClaimStatus::Rejected;
+                msg!("Claim {} rejected", claim_id);
+            }
+            None => {
+                return Err(KnomeeError::ClaimNotReadyToResolve.into());
+            }
+        }
     }
 
-    // Check consensus
-    let consensus_result = claim.consensus_reached(&governance.params);
+    claim.snapshot_slash_terms(&governance.params);
+    subject_identity.claim_resolved(
+        claim.is_appealable(governance.params.appeal_window, current_time),
+        current_time,
+    );
 
-    match consensus_result {
-        Some(true) => {
-            // APPROVED
-            claim.status = ClaimStatus::Approved;
+    if !matches!(claim.status, ClaimStatus::Approved) {
+        // A failed appeal only puts the appellant on cooldown, not the subject
+        if !matches!(claim.claim_type, ClaimType::Appeal) || claim.creator == claim.subject {
+            subject_identity.last_failed_claim_at = current_time;
+        } else {
+            let creator_identity = ctx
+                .accounts
+                .creator_identity
+                .as_mut()
+                .ok_or(KnomeeError::AppellantIdentityRequired)?;
+            creator_identity.last_failed_claim_at = current_time;
+        }
+    }
 
-            match claim.claim_type {
-                ClaimType::LinkToPrimary => {
-                    // LinkToPrimary approved - identity linking will happen in separate instruction
-                    msg!("LinkToPrimary claim approved");
-                }
-                ClaimType::NewPrimary => {
-                    // Upgrade to Primary ID
-                    subject_identity.tier = IdentityTier::PrimaryID;
-                    subject_identity.verified_at = current_time;
-                    msg!("NewPrimary claim approved - identity upgraded to PrimaryID");
-                }
-                ClaimType::DuplicateFlag => {
-                    // Both addresses downgraded to GreyGhost
-                    subject_identity.tier = IdentityTier::GreyGhost;
-                    subject_identity.verified_at = 0;
-                    msg!("DuplicateFlag claim approved - Sybil detected");
-                }
+    // Record the appeal's outcome on the original so its settlement can proceed
+    if matches!(claim.claim_type, ClaimType::Appeal) {
+        let appealed_claim = ctx
+            .accounts
+            .appealed_claim
+            .as_mut()
+            .ok_or(KnomeeError::AppealedClaimRequired)?;
+        appealed_claim.appeal_resolved = true;
+    }
+
+    // Clear challenge status on both flagged addresses, unless a later challenge took over
+    if matches!(claim.claim_type, ClaimType::DuplicateFlag) {
+        let related_identity = ctx
+            .accounts
+            .related_identity
+            .as_mut()
+            .ok_or(KnomeeError::RelatedIdentityRequired)?;
+        for identity in [&mut **subject_identity, &mut **related_identity] {
+            if identity.challenge_claim_id == claim_id {
+                identity.under_challenge = false;
+                identity.challenge_claim_id = 0;
             }
         }
-        Some(false) => {
-            // REJECTED
-            claim.status = ClaimStatus::Rejected;
-            subject_identity.last_failed_claim_at = current_time;
-            msg!("Claim {} rejected", claim_id);
+    }
+
+    ctx.accounts
+        .tier_token
+        .sync(subject_identity, ctx.bumps.tier_token.token_authority)?;
+
+    msg!("Claim {} resolved: {:?}", claim_id, claim.status);
+
+    // Pay the crank bounty if the treasury can cover it. Claims resolved before expiry
+    // must have drawn enough vouches, so creating a claim and resolving it at once
+    // cannot drain the treasury.
+    let bounty_earned = current_time >= claim.expires_at
+        || claim.vouch_count >= governance.params.resolution_bounty_min_votes;
+    let bounty = governance.params.resolution_bounty_lamports;
+    if let (Some(treasury_vault), Some(resolver_token_account), Some(token_program)) = (
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.resolver_token_account,
+        &ctx.accounts.token_program,
+    ) {
+        if bounty_earned && bounty > 0 && treasury_vault.amount >= bounty {
+            let signer_seeds: &[&[u8]] = &[GOVERNANCE_SEED, &[governance.bump]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: treasury_vault.to_account_info(),
+                        to: resolver_token_account.to_account_info(),
+                        authority: governance.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                bounty,
+            )?;
+            msg!("Resolution bounty paid: {} KNOW", bounty);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reverse the tier effects of a resolved claim and flip its outcome
+fn overturn_claim(original: &mut IdentityClaim, subject: &mut Identity, current_time: i64) {
+    match (original.claim_type, original.status) {
+        (ClaimType::NewPrimary, ClaimStatus::Approved)
+        | (ClaimType::DuplicateFlag, ClaimStatus::Rejected) => {
+            subject.tier = IdentityTier::GreyGhost;
+            subject.verified_at = 0;
         }
-        None => {
-            return Err(KnomeeError::ClaimNotReadyToResolve.into());
+        (ClaimType::NewPrimary, ClaimStatus::Rejected)
+        | (ClaimType::DuplicateFlag, ClaimStatus::Approved) => {
+            // Oracle status is not restored and must be re-granted by governance
+            subject.tier = IdentityTier::PrimaryID;
+            subject.verified_at = current_time;
+            subject.last_failed_claim_at = 0;
         }
+        (ClaimType::LinkToPrimary, ClaimStatus::Approved)
+            if subject.is_linked_to(&original.related_address) =>
+        {
+            // The link records become stale; anyone can close them with `unlink_identity`,
+            // which releases the Primary's linked slot
+            subject.tier = IdentityTier::GreyGhost;
+            subject.primary_address = subject.owner;
+            subject.verified_at = 0;
+        }
+        (ClaimType::LinkToPrimary, ClaimStatus::Rejected) => {
+            subject.last_failed_claim_at = 0;
+        }
+        _ => {}
     }
 
-    // Clear challenge status if applicable
-    if matches!(claim.claim_type, ClaimType::DuplicateFlag) {
-        subject_identity.under_challenge = false;
-        subject_identity.challenge_claim_id = 0;
+    original.status = match original.status {
+        ClaimStatus::Approved => ClaimStatus::Rejected,
+        ClaimStatus::Rejected => ClaimStatus::Approved,
+        status => status,
+    };
+}
+
+// ============================================================
+// APPEAL CLAIM
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(original_claim_id: u64, justification: String, stake_amount: u64)]
+pub struct AppealClaim<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        constraint = original_claim.claim_id == original_claim_id @ KnomeeError::InvalidClaimStatus
+    )]
+    pub original_claim: Account<'info, IdentityClaim>,
+
+    #[account(
+        init,
+        payer = appellant,
+        space = IdentityClaim::space(original_claim.platform.len(), justification.len()),
+        seeds = [CLAIM_SEED, &governance.initialized_at.to_le_bytes(), &next_claim_id().to_le_bytes()],
+        bump
+    )]
+    pub appeal: Account<'info, IdentityClaim>,
+
+    /// Identity the original claim (and so the appeal) is about
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, original_claim.subject.as_ref()],
+        bump = subject_identity.bump
+    )]
+    pub subject_identity: Account<'info, Identity>,
+
+    #[account(
+        seeds = [IDENTITY_SEED, appellant.key().as_ref()],
+        bump = appellant_identity.bump
+    )]
+    pub appellant_identity: Account<'info, Identity>,
+
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+
+    #[account(mut)]
+    pub appellant_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    /// Treasury vault (required when governance sets a claim fee)
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn appeal_claim(
+    ctx: Context<AppealClaim>,
+    original_claim_id: u64,
+    justification: String,
+    stake_amount: u64,
+) -> Result<()> {
+    require!(
+        justification.len() <= MAX_JUSTIFICATION_LEN,
+        KnomeeError::JustificationTooLong
+    );
+
+    let governance = &ctx.accounts.governance;
+    let original_claim = &mut ctx.accounts.original_claim;
+    let appeal = &mut ctx.accounts.appeal;
+    let appellant = ctx.accounts.appellant.key();
+    let current_time = governance.current_time();
+
+    require!(
+        original_claim.is_appealable(governance.params.appeal_window, current_time),
+        KnomeeError::ClaimNotAppealable
+    );
+
+    // Parties to the claim or anyone eligible to vote may appeal
+    require!(
+        appellant == original_claim.subject
+            || appellant == original_claim.related_address
+            || ctx.accounts.appellant_identity.tier.can_vote(),
+        KnomeeError::NotEligibleToAppeal
+    );
+
+    // Failed appeals put the appellant on cooldown
+    let appellant_identity = &ctx.accounts.appellant_identity;
+    if appellant_identity.last_failed_claim_at > 0 {
+        let cooldown = ClaimType::Appeal.cooldown_period(&governance.params);
+        require!(
+            current_time >= appellant_identity.last_failed_claim_at + cooldown,
+            KnomeeError::CooldownNotElapsed
+        );
     }
 
-    msg!("Claim {} resolved: {:?}", claim_id, claim.status);
+    // Appeals need at least the original claim type's threshold and stake multiplier
+    let threshold_bps = governance
+        .params
+        .appeal_threshold
+        .max(original_claim.claim_type.required_threshold(&governance.params));
+    let multiplier = ClaimType::Appeal
+        .required_stake_multiplier(&governance.params)
+        .max(original_claim.claim_type.required_stake_multiplier(&governance.params));
+    let min_stake = governance
+        .params
+        .min_stake_lamports
+        .checked_mul(multiplier)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    require!(stake_amount >= min_stake, KnomeeError::InsufficientStake);
+
+    // Transfer stake
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.appellant_token_account.to_account_info(),
+            to: ctx.accounts.stake_escrow.to_account_info(),
+            authority: ctx.accounts.appellant.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, stake_amount)?;
+
+    collect_claim_fee(
+        governance.params.claim_fee_lamports,
+        &ctx.accounts.appellant_token_account,
+        &ctx.accounts.appellant,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Track the open claim on its subject (blocks migration until it settles)
+    ctx.accounts
+        .subject_identity
+        .claim_opened()
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    // Initialize appeal claim against the same subject
+    let claim_id = next_claim_id();
+    appeal.claim_id = claim_id;
+    appeal.claim_type = ClaimType::Appeal;
+    appeal.status = ClaimStatus::Active;
+    appeal.subject = original_claim.subject;
+    appeal.creator = ctx.accounts.appellant.key();
+    appeal.related_address = original_claim.related_address;
+    appeal.platform = original_claim.platform.clone();
+    appeal.justification = justification;
+    appeal.created_at = current_time;
+    appeal.expires_at = current_time + governance.params.claim_expiry_duration;
+    appeal.total_votes_for = 0;
+    appeal.total_votes_against = 0;
+    appeal.total_stake = stake_amount;
+    appeal.creator_stake = stake_amount;
+    appeal.creator_stake_settled = false;
+    appeal.total_slashed = 0;
+    appeal.stake_for = 0;
+    appeal.stake_against = 0;
+    appeal.vouch_count = 0;
+    appeal.settled_vouch_count = 0;
+    appeal.closed_vouch_count = 0;
+    appeal.rewards_distributed = false;
+    appeal.stake_weighting = governance.params.stake_weighting;
+    appeal.stake_weight_cap = governance.params.stake_weight_cap;
+    appeal.resolved_at = 0;
+    appeal.slash_terms = SlashTerms::default();
+    appeal.appeal_of = original_claim.claim_id;
+    appeal.appeal_claim_id = 0;
+    appeal.appeal_resolved = false;
+    appeal.threshold_bps = threshold_bps;
+    appeal.oracle_only = governance.params.appeal_oracle_only;
+    appeal.created_slot = Clock::get()?.slot;
+    appeal.jury_selection_bps = governance.params.jury_selection_bps;
+    appeal.bump = ctx.bumps.appeal;
+
+    // Link original to its appeal (one appeal per claim)
+    original_claim.appeal_claim_id = claim_id;
+
+    msg!("Appeal claim created: {}", claim_id);
+    msg!("Appealing claim: {}", original_claim_id);
+    msg!("Appellant: {}", appellant);
+    msg!("Oracle-only voting: {}", appeal.oracle_only);
+    msg!("Threshold: {} bps", threshold_bps);
 
     Ok(())
 }
@@ -711,6 +1622,12 @@ pub fn resolve_consensus(
 #[derive(Accounts)]
 #[instruction(claim_id: u64)]
 pub struct ClaimRewards<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
     #[account(
         mut,
         constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
@@ -727,56 +1644,464 @@ pub struct ClaimRewards<'info> {
     )]
     pub vouch: Account<'info, Vouch>,
 
-    #[account(mut)]
-    pub voucher: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, voucher.key().as_ref()],
+        bump = voucher_identity.bump
+    )]
+    pub voucher_identity: Account<'info, Identity>,
 
-    #[account(mut)]
-    pub voucher_token_account: Account<'info, TokenAccount>,
+    /// CHECK: Voucher whose vouch is settled; does not sign so anyone can settle
+    pub voucher: UncheckedAccount<'info>,
 
-    #[account(mut)]
+    /// Anyone may settle a vouch on a resolved claim, so losers cannot avoid slashing
+    /// or the accuracy update by never claiming
+    pub settler: Signer<'info>,
+
+    /// Voucher's stake account, where the vouch lock is released
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, voucher.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
     pub stake_escrow: Account<'info, TokenAccount>,
 
+    /// Receives the treasury share of slashed stake
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    /// KNOW mint (the burn share of slashed stake is burned)
+    #[account(
+        mut,
+        address = stake_escrow.mint @ KnomeeError::InvalidKnowMint
+    )]
+    pub know_mint: Account<'info, Mint>,
+
     pub token_program: Program<'info, Token>,
+
+    /// Voucher's tier token (burned if an Oracle is demoted)
+    pub tier_token: TierTokenAccounts<'info>,
 }
 
+/// Settle a vouch on a resolved claim (permissionless crank)
 pub fn claim_rewards(
     ctx: Context<ClaimRewards>,
     claim_id: u64,
 ) -> Result<()> {
-    let claim = &ctx.accounts.claim;
+    let params = &ctx.accounts.governance.params;
+    let claim = &mut ctx.accounts.claim;
+    let terms = claim.slash_terms;
     let vouch = &mut ctx.accounts.vouch;
+    let voucher_identity = &mut ctx.accounts.voucher_identity;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let escrow_seeds: &[&[u8]] = &[STAKE_ESCROW_SEED, &[ctx.bumps.stake_escrow]];
+
+    // Wait until an appeal can no longer flip the outcome, so each side is paid once
+    require!(
+        claim.is_final(params.appeal_window, ctx.accounts.governance.current_time()),
+        KnomeeError::ClaimNotFinal
+    );
 
     // Determine if this voucher was on winning side
-    let is_winner = match claim.status {
-        ClaimStatus::Approved => vouch.supports,
-        ClaimStatus::Rejected => !vouch.supports,
-        _ => false,
-    };
+    let is_winner = vouch.is_winner(claim);
 
     if is_winner {
-        // Return stake + share of slashed stakes
-        // Simplified: just return stake for now
-        // In production, calculate proportional reward from slashed stakes
-        let reward = vouch.stake;
-
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.stake_escrow.to_account_info(),
-                to: ctx.accounts.voucher_token_account.to_account_info(),
-                authority: ctx.accounts.stake_escrow.to_account_info(), // Would use PDA signer
-            },
-        );
-        token::transfer(transfer_ctx, reward)?;
+        // Unlock stake + credit a pro-rata share of the winners' portion of slashed stake
+        // (including a rejected claim's creator stake)
+        let share = claim.winners_pool() * (vouch.stake as u128) / (claim.winning_stake() as u128);
+        let reward = vouch
+            .stake
+            .checked_add(share as u64)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+        stake_account
+            .settle(vouch.stake, 0, share as u64)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
 
         vouch.reward_amount = reward;
-        msg!("Reward claimed: {} KNOW", reward);
+        msg!("Reward claimed: {} KNOW (stake {} + share {})", reward, vouch.stake, share);
+    } else if matches!(claim.status, ClaimStatus::Approved | ClaimStatus::Rejected) {
+        // Losing side - slash the stake balance (rounded up so the winners' pool is always covered),
+        // send the treasury share, burn the burn share and leave the rest in escrow for winners
+        let bps = BASIS_POINTS as u128;
+        let slashed = (vouch.stake as u128 * terms.voter_slash_bps as u128).div_ceil(bps) as u64;
+        let refund = vouch.stake - slashed;
+        let to_treasury = (slashed as u128 * terms.treasury_bps as u128 / bps) as u64;
+        let to_burn = (slashed as u128 * terms.burn_bps as u128 / bps) as u64;
+
+        stake_account
+            .settle(vouch.stake, slashed, 0)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+        if to_treasury > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.stake_escrow.to_account_info(),
+                        to: ctx.accounts.treasury_vault.to_account_info(),
+                        authority: ctx.accounts.stake_escrow.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                to_treasury,
+            )?;
+        }
+        if to_burn > 0 {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.know_mint.to_account_info(),
+                        from: ctx.accounts.stake_escrow.to_account_info(),
+                        authority: ctx.accounts.stake_escrow.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                to_burn,
+            )?;
+        }
+
+        claim.total_slashed = claim
+            .total_slashed
+            .checked_add(slashed)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+        vouch.reward_amount = refund;
+        msg!("Stake slashed: {} KNOW, unlocked: {} KNOW", slashed, refund);
+        msg!("Slash split - treasury: {}, burned: {}", to_treasury, to_burn);
+
+        // Oracles on the losing side of a Sybil challenge also lose part of their bond
+        if matches!(claim.claim_type, ClaimType::DuplicateFlag) && voucher_identity.is_oracle() {
+            let bond_slashed = stake_account
+                .slash_bond(terms.oracle_bond_slash_bps)
+                .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+            if bond_slashed > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.stake_escrow.to_account_info(),
+                            to: ctx.accounts.treasury_vault.to_account_info(),
+                            authority: ctx.accounts.stake_escrow.to_account_info(),
+                        },
+                        &[escrow_seeds],
+                    ),
+                    bond_slashed,
+                )?;
+                msg!("Oracle bond slashed: {} KNOW", bond_slashed);
+            }
+
+            if stake_account.bonded < params.min_oracle_bond {
+                voucher_identity.tier = IdentityTier::PrimaryID;
+                ctx.accounts
+                    .tier_token
+                    .sync(voucher_identity, ctx.bumps.tier_token.token_authority)?;
+                msg!("Oracle demoted to PrimaryID: bond below minimum");
+            }
+        }
     } else {
-        // Losing side - stake is slashed (already in escrow, will be burned/redistributed)
-        msg!("Stake slashed - no reward");
+        // Expired without consensus - no winning side, full unlock
+        stake_account
+            .settle(vouch.stake, 0, 0)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+        vouch.reward_amount = vouch.stake;
+        msg!("Claim expired - stake unlocked");
+    }
+
+    // Update voucher reputation (expired claims have no winning side)
+    if matches!(claim.status, ClaimStatus::Approved | ClaimStatus::Rejected) {
+        voucher_identity.votes_cast = voucher_identity
+            .votes_cast
+            .checked_add(1)
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+        if is_winner {
+            voucher_identity.votes_won = voucher_identity
+                .votes_won
+                .checked_add(1)
+                .ok_or(KnomeeError::ArithmeticOverflow)?;
+            voucher_identity.stake_won = voucher_identity
+                .stake_won
+                .checked_add(vouch.reward_amount)
+                .ok_or(KnomeeError::ArithmeticOverflow)?;
+        } else {
+            voucher_identity.stake_slashed = voucher_identity
+                .stake_slashed
+                .checked_add(vouch.stake - vouch.reward_amount)
+                .ok_or(KnomeeError::ArithmeticOverflow)?;
+        }
+        msg!(
+            "Voucher accuracy: {}/{} ({} bps)",
+            voucher_identity.votes_won,
+            voucher_identity.votes_cast,
+            voucher_identity.accuracy_bps()
+        );
     }
 
     vouch.rewards_claimed = true;
+    claim.settled_vouch_count = claim
+        .settled_vouch_count
+        .checked_add(1)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+// ============================================================
+// SETTLE CREATOR STAKE
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct SettleCreatorStake<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.status.is_resolved() @ KnomeeError::ClaimNotReadyToResolve,
+        constraint = !claim.creator_stake_settled @ KnomeeError::RewardsAlreadyClaimed
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    /// Creator's KNOW token account, refunded unless the claim was rejected
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == claim.creator @ KnomeeError::NotClaimCreator,
+        constraint = creator_token_account.mint == stake_escrow.mint @ KnomeeError::InvalidKnowMint
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    /// Receives the treasury share of a slashed creator stake
+    #[account(
+        mut,
+        seeds = [TREASURY_VAULT_SEED],
+        bump
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    /// KNOW mint (the burn share of a slashed creator stake is burned)
+    #[account(
+        mut,
+        address = stake_escrow.mint @ KnomeeError::InvalidKnowMint
+    )]
+    pub know_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Refund the creator's stake on a final claim, or slash it if the claim was rejected
+/// (permissionless crank)
+pub fn settle_creator_stake(ctx: Context<SettleCreatorStake>, claim_id: u64) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let claim = &mut ctx.accounts.claim;
+    let escrow_seeds: &[&[u8]] = &[STAKE_ESCROW_SEED, &[ctx.bumps.stake_escrow]];
+
+    // An appeal could still flip the outcome
+    require!(
+        claim.is_final(governance.params.appeal_window, governance.current_time()),
+        KnomeeError::ClaimNotFinal
+    );
+
+    let slashed = claim.slashed_creator_stake();
+    let refund = claim.creator_stake - slashed;
+    let bps = BASIS_POINTS as u128;
+    let terms = claim.slash_terms;
+    let to_burn = (slashed as u128 * terms.burn_bps as u128 / bps) as u64;
+    // The winners' share stays in escrow for `claim_rewards`, or goes to the treasury
+    // if no vouch won
+    let to_treasury = if claim.winning_stake() > 0 {
+        (slashed as u128 * terms.treasury_bps as u128 / bps) as u64
+    } else {
+        slashed - to_burn
+    };
+
+    if refund > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.creator_token_account.to_account_info(),
+                    authority: ctx.accounts.stake_escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            refund,
+        )?;
+    }
+    if to_treasury > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.treasury_vault.to_account_info(),
+                    authority: ctx.accounts.stake_escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            to_treasury,
+        )?;
+    }
+    if to_burn > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.know_mint.to_account_info(),
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    authority: ctx.accounts.stake_escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            to_burn,
+        )?;
+    }
+
+    claim.total_slashed = claim
+        .total_slashed
+        .checked_add(slashed)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    claim.creator_stake_settled = true;
+
+    msg!("Creator stake on claim {} - refunded: {}, slashed: {}", claim_id, refund, slashed);
+    msg!("Slash split - treasury: {}, burned: {}", to_treasury, to_burn);
+
+    Ok(())
+}
+
+// ============================================================
+// CLOSE SETTLED ACCOUNTS (RENT RECLAMATION)
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct CloseVouch<'info> {
+    #[account(
+        mut,
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.settled_vouch_count >= claim.vouch_count @ KnomeeError::VouchesNotSettled
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    #[account(
+        mut,
+        close = voucher,
+        seeds = [VOUCH_SEED, &claim_id.to_le_bytes(), voucher.key().as_ref()],
+        bump = vouch.bump,
+        constraint = vouch.rewards_claimed @ KnomeeError::VouchesNotSettled
+    )]
+    pub vouch: Account<'info, Vouch>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// CHECK: KNOW rewards pool PDA, which may not be initialized yet
+    #[account(
+        seeds = [REWARDS_POOL_SEED],
+        bump
+    )]
+    pub rewards_pool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub voucher: Signer<'info>,
+}
+
+/// Close a settled vouch once every vouch on the claim has claimed rewards (rent to voucher)
+pub fn close_vouch(ctx: Context<CloseVouch>, claim_id: u64) -> Result<()> {
+    let claim = &mut ctx.accounts.claim;
+
+    // Closing would forfeit an unpaid KNOW voting reward once the rewards pool is live
+    let rewards_pool = &ctx.accounts.rewards_pool;
+    let pool_live = rewards_pool.owner == &crate::ID && !rewards_pool.data_is_empty();
+    require!(
+        !pool_live
+            || ctx.accounts.vouch.know_reward_paid
+            || ctx.accounts.vouch.voting_reward(claim, &ctx.accounts.governance.params) == 0,
+        KnomeeError::VotingRewardNotClaimed
+    );
+
+    claim.closed_vouch_count = claim
+        .closed_vouch_count
+        .checked_add(1)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!(
+        "Vouch closed on claim {} ({}/{})",
+        claim_id,
+        claim.closed_vouch_count,
+        claim.vouch_count
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct CloseClaim<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        close = creator,
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.status.is_resolved() @ KnomeeError::ClaimNotReadyToResolve,
+        constraint = claim.creator == creator.key() @ KnomeeError::NotClaimCreator,
+        constraint = claim.closed_vouch_count >= claim.vouch_count @ KnomeeError::VouchesNotClosed,
+        constraint = claim.closed_evidence_count >= claim.evidence_count @ KnomeeError::EvidenceNotClosed,
+        constraint = claim.creator_stake_settled @ KnomeeError::CreatorStakeNotSettled
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+/// Close a resolved claim after all its vouches and evidence are closed and the creator's
+/// stake is settled (rent to creator)
+pub fn close_claim(ctx: Context<CloseClaim>, claim_id: u64) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let claim = &ctx.accounts.claim;
+    let current_time = governance.current_time();
+
+    // Keep the claim around while it can still be appealed or an appeal is pending
+    require!(
+        claim.is_final(governance.params.appeal_window, current_time),
+        KnomeeError::ClaimStillAppealable
+    );
+
+    msg!("Claim {} closed", claim_id);
 
     Ok(())
 }
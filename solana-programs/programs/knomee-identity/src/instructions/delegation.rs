@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use std::collections::BTreeSet;
+use crate::{constants::*, errors::KnomeeError, state::*};
+
+// ============================================================
+// DELEGATE VOTE
+// ============================================================
+
+#[derive(Accounts)]
+pub struct DelegateVote<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, delegator.key().as_ref()],
+        bump = delegator_identity.bump,
+        constraint = matches!(delegator_identity.tier, IdentityTier::PrimaryID) @ KnomeeError::NotAPrimaryId,
+        constraint = delegator_identity.delegated_to == Pubkey::default() @ KnomeeError::AlreadyDelegated
+    )]
+    pub delegator_identity: Account<'info, Identity>,
+
+    #[account(
+        seeds = [IDENTITY_SEED, delegate_identity.owner.as_ref()],
+        bump = delegate_identity.bump,
+        constraint = delegate_identity.tier.can_vote() @ KnomeeError::InsufficientVotingWeight,
+        constraint = delegate_identity.delegated_to == Pubkey::default() @ KnomeeError::AlreadyDelegated,
+        constraint = delegate_identity.owner != delegator.key() @ KnomeeError::CannotDelegateToSelf
+    )]
+    pub delegate_identity: Account<'info, Identity>,
+
+    #[account(
+        init,
+        payer = delegator,
+        space = Delegation::LEN,
+        seeds = [DELEGATION_SEED, delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn delegate_vote(ctx: Context<DelegateVote>) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let delegator_identity = &mut ctx.accounts.delegator_identity;
+    let current_time = ctx.accounts.governance.current_time();
+
+    delegation.delegator = ctx.accounts.delegator.key();
+    delegation.delegate = ctx.accounts.delegate_identity.owner;
+    delegation.delegated_at = current_time;
+    delegation.bump = ctx.bumps.delegation;
+
+    delegator_identity.delegated_to = delegation.delegate;
+
+    msg!("Vote delegated from {} to {}", delegation.delegator, delegation.delegate);
+
+    Ok(())
+}
+
+// ============================================================
+// REVOKE DELEGATION
+// ============================================================
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, delegator.key().as_ref()],
+        bump = delegator_identity.bump
+    )]
+    pub delegator_identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [DELEGATION_SEED, delegator.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let delegator_identity = &mut ctx.accounts.delegator_identity;
+
+    // Claims opened while delegated stay off-limits for direct votes (no double counting)
+    delegator_identity.delegated_to = Pubkey::default();
+    delegator_identity.delegation_ended_at = ctx.accounts.governance.current_time();
+
+    msg!("Delegation revoked by {}", ctx.accounts.delegator.key());
+
+    Ok(())
+}
+
+// ============================================================
+// HELPERS
+// ============================================================
+
+/// Sum the vote weight delegated to `voucher` for `claim`.
+///
+/// `remaining_accounts` holds `[Delegation, delegator Identity]` pairs. Delegators that are
+/// the claim's subject, related address or creator have a conflict of interest and are skipped.
+/// Oracle-only claims take no delegated weight, and on jury claims only drawn jurors count.
+// `usize::is_multiple_of` needs Rust 1.87, newer than the SBF toolchain
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+pub(crate) fn delegated_weight<'info>(
+    claim: &IdentityClaim,
+    jury: Option<&Jury>,
+    voucher: &Pubkey,
+    params: &GovernanceParams,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<u64> {
+    require!(
+        remaining_accounts.len() % 2 == 0,
+        KnomeeError::InvalidDelegationAccounts
+    );
+
+    // Delegators are Primaries, who cannot vote on Oracle-only claims themselves
+    if claim.oracle_only {
+        return Ok(0);
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut total: u64 = 0;
+
+    for pair in remaining_accounts.chunks(2) {
+        let delegation: Account<Delegation> = Account::try_from(&pair[0])?;
+        let delegator_identity: Account<Identity> = Account::try_from(&pair[1])?;
+
+        require!(
+            delegation.delegate == *voucher
+                && delegator_identity.owner == delegation.delegator
+                && seen.insert(delegation.delegator),
+            KnomeeError::InvalidDelegationAccounts
+        );
+
+        // Only delegations made before the claim opened, from current Primaries
+        if delegation.delegated_at >= claim.created_at
+            || !matches!(delegator_identity.tier, IdentityTier::PrimaryID)
+        {
+            continue;
+        }
+
+        if delegation.delegator == claim.subject
+            || delegation.delegator == claim.related_address
+            || delegation.delegator == claim.creator
+        {
+            msg!("Delegation from {} excluded (conflict of interest)", delegation.delegator);
+            continue;
+        }
+
+        if jury.is_some_and(|jury| !jury.is_juror(&delegation.delegator)) {
+            msg!("Delegation from {} excluded (not a juror)", delegation.delegator);
+            continue;
+        }
+
+        total = total
+            .checked_add(delegator_identity.voting_weight(params))
+            .ok_or(KnomeeError::ArithmeticOverflow)?;
+    }
+
+    Ok(total)
+}
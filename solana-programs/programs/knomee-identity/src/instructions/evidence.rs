@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::KnomeeError, events::EvidenceSubmitted, state::*};
+
+// ============================================================
+// SUBMIT EVIDENCE
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64, content_hash: [u8; 32], uri: String, media_type: String)]
+pub struct SubmitEvidence<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.status == ClaimStatus::Active @ KnomeeError::ClaimAlreadyResolved
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    #[account(
+        init,
+        payer = submitter,
+        space = Evidence::space(uri.len(), media_type.len()),
+        seeds = [EVIDENCE_SEED, &claim_id.to_le_bytes(), &claim.evidence_count.to_le_bytes()],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
+
+    /// Submitter's vouch on this claim (required unless the submitter is the subject or creator)
+    #[account(
+        seeds = [VOUCH_SEED, &claim_id.to_le_bytes(), submitter.key().as_ref()],
+        bump = vouch.bump
+    )]
+    pub vouch: Option<Account<'info, Vouch>>,
+
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Append an evidence entry to an active claim.
+/// Open to the claim's subject and creator, and to anyone who has vouched on it.
+pub fn submit_evidence(
+    ctx: Context<SubmitEvidence>,
+    claim_id: u64,
+    content_hash: [u8; 32],
+    uri: String,
+    media_type: String,
+) -> Result<()> {
+    let claim = &mut ctx.accounts.claim;
+    let evidence = &mut ctx.accounts.evidence;
+    let submitter = ctx.accounts.submitter.key();
+    let current_time = ctx.accounts.governance.current_time();
+
+    // Evidence is only accepted during the voting window
+    require!(
+        current_time < claim.expires_at,
+        KnomeeError::ClaimExpired
+    );
+
+    require!(
+        submitter == claim.subject
+            || submitter == claim.creator
+            || ctx.accounts.vouch.is_some(),
+        KnomeeError::NotAClaimParticipant
+    );
+
+    require!(
+        !uri.is_empty() && uri.len() <= MAX_EVIDENCE_URI_LEN,
+        KnomeeError::InvalidEvidence
+    );
+    require!(
+        media_type.len() <= MAX_EVIDENCE_MEDIA_TYPE_LEN,
+        KnomeeError::InvalidEvidence
+    );
+    require!(
+        content_hash != [0u8; 32],
+        KnomeeError::InvalidEvidence
+    );
+    require!(
+        claim.evidence_count < MAX_EVIDENCE_PER_CLAIM,
+        KnomeeError::TooMuchEvidence
+    );
+
+    evidence.claim_id = claim_id;
+    evidence.index = claim.evidence_count;
+    evidence.submitter = submitter;
+    evidence.content_hash = content_hash;
+    evidence.uri = uri;
+    evidence.media_type = media_type;
+    evidence.submitted_at = current_time;
+    evidence.bump = ctx.bumps.evidence;
+
+    claim.evidence_count += 1;
+
+    emit!(EvidenceSubmitted {
+        claim_id,
+        index: evidence.index,
+        submitter,
+        content_hash,
+        uri: evidence.uri.clone(),
+        media_type: evidence.media_type.clone(),
+        timestamp: current_time,
+    });
+
+    msg!("Evidence {} submitted on claim {} by {}", evidence.index, claim_id, submitter);
+
+    Ok(())
+}
+
+// ============================================================
+// CLOSE EVIDENCE
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64, index: u32)]
+pub struct CloseEvidence<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.status.is_resolved() @ KnomeeError::ClaimNotReadyToResolve
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    #[account(
+        mut,
+        close = submitter,
+        seeds = [EVIDENCE_SEED, &claim_id.to_le_bytes(), &index.to_le_bytes()],
+        bump = evidence.bump,
+        constraint = evidence.submitter == submitter.key() @ KnomeeError::NotEvidenceSubmitter
+    )]
+    pub evidence: Account<'info, Evidence>,
+
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+}
+
+/// Close an evidence entry once its claim can no longer be appealed (rent to submitter)
+pub fn close_evidence(ctx: Context<CloseEvidence>, claim_id: u64, index: u32) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let claim = &mut ctx.accounts.claim;
+
+    // Appeal voters review the same evidence
+    require!(
+        claim.is_final(governance.params.appeal_window, governance.current_time()),
+        KnomeeError::ClaimStillAppealable
+    );
+
+    claim.closed_evidence_count = claim
+        .closed_evidence_count
+        .checked_add(1)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!("Evidence {} closed on claim {}", index, claim_id);
+
+    Ok(())
+}
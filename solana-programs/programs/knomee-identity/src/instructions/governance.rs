@@ -26,6 +26,8 @@ pub fn initialize_governance(
     ctx: Context<InitializeGovernance>,
     params: GovernanceParams,
 ) -> Result<()> {
+    validate_params(&params)?;
+
     let governance = &mut ctx.accounts.governance;
     let current_time = Clock::get()?.unix_timestamp;
 
@@ -64,9 +66,18 @@ pub fn update_governance_params(
     ctx: Context<UpdateGovernance>,
     params: GovernanceParams,
 ) -> Result<()> {
+    validate_params(&params)?;
+
     let governance = &mut ctx.accounts.governance;
+    governance.params = params;
+
+    msg!("Governance parameters updated");
+
+    Ok(())
+}
 
-    // Validate parameters
+/// Reject parameter sets that would break consensus or settlement
+fn validate_params(params: &GovernanceParams) -> Result<()> {
     require!(
         params.link_threshold >= 5100 && params.link_threshold <= 10000,
         KnomeeError::InvalidThreshold
@@ -79,10 +90,61 @@ pub fn update_governance_params(
         params.duplicate_threshold >= 5100 && params.duplicate_threshold <= 10000,
         KnomeeError::InvalidThreshold
     );
-
-    governance.params = params;
-
-    msg!("Governance parameters updated");
+    require!(
+        params.appeal_threshold >= 5100 && params.appeal_threshold <= 10000,
+        KnomeeError::InvalidThreshold
+    );
+    require!(
+        params.recovery_threshold >= 5100 && params.recovery_threshold <= 10000,
+        KnomeeError::InvalidThreshold
+    );
+    require!(
+        params.stake_weighting != StakeWeighting::Capped || params.stake_weight_cap > 0,
+        KnomeeError::InvalidStakeWeightCap
+    );
+    require!(
+        params.max_weight_share_bps > 0 && params.max_weight_share_bps <= BASIS_POINTS,
+        KnomeeError::InvalidWeightShare
+    );
+    require!(
+        params.accuracy_floor_bps <= BASIS_POINTS,
+        KnomeeError::InvalidAccuracyFloor
+    );
+    require!(
+        params.slash_winners_bps as u32
+            + params.slash_treasury_bps as u32
+            + params.slash_burn_bps as u32
+            == BASIS_POINTS as u32,
+        KnomeeError::InvalidSlashSplit
+    );
+    require!(
+        params.stake_unbonding_period >= 0,
+        KnomeeError::InvalidUnbondingPeriod
+    );
+    require!(
+        params.link_slash_bps <= BASIS_POINTS
+            && params.primary_slash_bps <= BASIS_POINTS
+            && params.duplicate_slash_bps <= BASIS_POINTS
+            && params.appeal_slash_bps <= BASIS_POINTS
+            && params.sybil_slash_bps <= BASIS_POINTS,
+        KnomeeError::InvalidSlashRate
+    );
+    require!(
+        params.oracle_bond_slash_bps <= BASIS_POINTS,
+        KnomeeError::InvalidSlashRate
+    );
+    require!(
+        params.jury_selection_bps <= BASIS_POINTS,
+        KnomeeError::InvalidJurySelection
+    );
+    require!(
+        params.appeal_stake_multiplier > 0 && params.recovery_stake_multiplier > 0,
+        KnomeeError::InvalidStakeMultiplier
+    );
+    require!(
+        params.max_linked_per_primary > 0,
+        KnomeeError::InvalidLinkedLimit
+    );
 
     Ok(())
 }
@@ -155,3 +217,85 @@ pub fn renounce_god_mode(ctx: Context<RenounceGodMode>) -> Result<()> {
 
     Ok(())
 }
+
+// ============================================================
+// REGISTER PLATFORM
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(platform: String)]
+pub struct RegisterPlatform<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RegisteredPlatform::LEN,
+        seeds = [PLATFORM_SEED, platform.as_bytes()],
+        bump
+    )]
+    pub registered_platform: Account<'info, RegisteredPlatform>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_platform(ctx: Context<RegisterPlatform>, platform: String) -> Result<()> {
+    require_canonical_platform(&platform)?;
+
+    let registered_platform = &mut ctx.accounts.registered_platform;
+
+    registered_platform.name = platform;
+    registered_platform.enabled = true;
+    registered_platform.registered_at = ctx.accounts.governance.current_time();
+    registered_platform.bump = ctx.bumps.registered_platform;
+
+    msg!("Platform registered: {}", registered_platform.name);
+
+    Ok(())
+}
+
+// ============================================================
+// ENABLE / DISABLE PLATFORM
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(platform: String)]
+pub struct SetPlatformEnabled<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_SEED, platform.as_bytes()],
+        bump = registered_platform.bump
+    )]
+    pub registered_platform: Account<'info, RegisteredPlatform>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_platform_enabled(
+    ctx: Context<SetPlatformEnabled>,
+    platform: String,
+    enabled: bool,
+) -> Result<()> {
+    let registered_platform = &mut ctx.accounts.registered_platform;
+
+    registered_platform.enabled = enabled;
+
+    msg!("Platform {} enabled: {}", platform, enabled);
+
+    Ok(())
+}
@@ -1,5 +1,13 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, errors::KnomeeError, state::*};
+use crate::{
+    constants::*,
+    errors::KnomeeError,
+    instructions::{
+        identity_token::*,
+        stake::{move_oracle_bond, require_stake_settled},
+    },
+    state::*,
+};
 
 // ============================================================
 // INITIALIZE IDENTITY
@@ -20,6 +28,15 @@ pub struct InitializeIdentity<'info> {
     /// CHECK: Can be any address
     pub owner: UncheckedAccount<'info>,
 
+    /// Must be empty: keys that migrated their identity away are revoked
+    /// CHECK: Only checked for emptiness
+    #[account(
+        seeds = [MIGRATION_SEED, owner.key().as_ref()],
+        bump,
+        constraint = migration_record.data_is_empty() @ KnomeeError::IdentityRevoked
+    )]
+    pub migration_record: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -41,6 +58,17 @@ pub fn initialize_identity(ctx: Context<InitializeIdentity>) -> Result<()> {
     identity.oracle_decay_start = 0;
     identity.linked_count = 0;
     identity.last_failed_claim_at = 0;
+    identity.votes_cast = 0;
+    identity.votes_won = 0;
+    identity.stake_won = 0;
+    identity.stake_slashed = 0;
+    identity.token_tier = IdentityTier::GreyGhost;
+    identity.primary_reward_claimed = false;
+    identity.last_ubi_epoch = 0;
+    identity.delegated_to = Pubkey::default();
+    identity.delegation_ended_at = 0;
+    identity.open_claims = 0;
+    identity.last_claim_resolved_at = 0;
     identity.bump = ctx.bumps.identity;
 
     msg!("Identity initialized for: {}", identity.owner);
@@ -68,8 +96,17 @@ pub struct UpgradeToOracle<'info> {
     )]
     pub identity: Account<'info, Identity>,
 
+    /// Identity owner's stake account holding the Oracle bond
+    #[account(
+        seeds = [STAKE_ACCOUNT_SEED, identity.owner.as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
     #[account(constraint = authority.key() == governance.authority @ KnomeeError::UnauthorizedGovernance)]
     pub authority: Signer<'info>,
+
+    pub tier_token: TierTokenAccounts<'info>,
 }
 
 pub fn upgrade_to_oracle(ctx: Context<UpgradeToOracle>) -> Result<()> {
@@ -83,9 +120,19 @@ pub fn upgrade_to_oracle(ctx: Context<UpgradeToOracle>) -> Result<()> {
         KnomeeError::MustBePrimaryToUpgrade
     );
 
+    // Oracles must keep a minimum bonded stake
+    require!(
+        ctx.accounts.stake_account.bonded >= governance.params.min_oracle_bond,
+        KnomeeError::OracleBondTooLow
+    );
+
     identity.tier = IdentityTier::Oracle;
     identity.oracle_decay_start = current_time;
 
+    ctx.accounts
+        .tier_token
+        .sync(identity, ctx.bumps.tier_token.token_authority)?;
+
     msg!("Identity upgraded to Oracle: {}", identity.owner);
     msg!("Oracle decay starts at: {}", current_time);
 
@@ -97,7 +144,7 @@ pub fn upgrade_to_oracle(ctx: Context<UpgradeToOracle>) -> Result<()> {
 // ============================================================
 
 #[derive(Accounts)]
-#[instruction(platform: String)]
+#[instruction(platform: String, index: u16)]
 pub struct LinkIdentity<'info> {
     #[account(
         seeds = [GOVERNANCE_SEED],
@@ -120,6 +167,13 @@ pub struct LinkIdentity<'info> {
     )]
     pub linked_identity: Account<'info, Identity>,
 
+    #[account(
+        seeds = [PLATFORM_SEED, platform.as_bytes()],
+        bump = registered_platform.bump,
+        constraint = registered_platform.enabled @ KnomeeError::PlatformNotRecognized
+    )]
+    pub registered_platform: Account<'info, RegisteredPlatform>,
+
     #[account(
         init,
         payer = payer,
@@ -127,41 +181,69 @@ pub struct LinkIdentity<'info> {
         seeds = [
             LINKED_IDENTITY_SEED,
             primary_identity.owner.as_ref(),
-            platform.as_bytes()
+            platform.as_bytes(),
+            &index.to_le_bytes()
         ],
         bump
     )]
     pub linked_identity_record: Account<'info, LinkedIdentity>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = LinkedAddressLookup::LEN,
+        seeds = [LINKED_ADDRESS_SEED, linked_identity.owner.as_ref()],
+        bump
+    )]
+    pub linked_address_lookup: Account<'info, LinkedAddressLookup>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    pub tier_token: TierTokenAccounts<'info>,
 }
 
-pub fn link_identity(ctx: Context<LinkIdentity>, platform: String) -> Result<()> {
-    require!(
-        platform.len() <= MAX_PLATFORM_NAME_LEN,
-        KnomeeError::PlatformNameTooLong
-    );
+pub fn link_identity(ctx: Context<LinkIdentity>, platform: String, index: u16) -> Result<()> {
+    require_canonical_platform(&platform)?;
 
+    let governance = &ctx.accounts.governance;
     let primary_identity = &mut ctx.accounts.primary_identity;
     let linked_identity = &mut ctx.accounts.linked_identity;
     let linked_record = &mut ctx.accounts.linked_identity_record;
-    let current_time = ctx.accounts.governance.current_time();
+    let lookup = &mut ctx.accounts.linked_address_lookup;
+    let current_time = governance.current_time();
+
+    require!(
+        primary_identity.linked_count < governance.params.max_linked_per_primary,
+        KnomeeError::MaxLinkedIdentitiesReached
+    );
+    require!(index < MAX_LINK_INDEX, KnomeeError::InvalidLinkIndex);
 
     // Update linked identity
     linked_identity.tier = IdentityTier::LinkedID;
     linked_identity.primary_address = primary_identity.owner;
     linked_identity.verified_at = current_time;
+    ctx.accounts
+        .tier_token
+        .sync(linked_identity, ctx.bumps.tier_token.token_authority)?;
 
     // Create linked identity record
     linked_record.primary_address = primary_identity.owner;
     linked_record.linked_address = linked_identity.owner;
     linked_record.platform = platform.clone();
+    linked_record.index = index;
     linked_record.linked_at = current_time;
     linked_record.bump = ctx.bumps.linked_identity_record;
 
+    // Create reverse lookup
+    lookup.linked_address = linked_identity.owner;
+    lookup.primary_address = primary_identity.owner;
+    lookup.platform = platform.clone();
+    lookup.index = index;
+    lookup.bump = ctx.bumps.linked_address_lookup;
+
     // Increment linked count on primary
     primary_identity.linked_count = primary_identity
         .linked_count
@@ -169,10 +251,549 @@ pub fn link_identity(ctx: Context<LinkIdentity>, platform: String) -> Result<()>
         .ok_or(KnomeeError::ArithmeticOverflow)?;
 
     msg!(
-        "Linked identity {} to primary {} on platform: {}",
+        "Linked identity {} to primary {} on platform: {} (#{})",
+        linked_identity.owner,
+        primary_identity.owner,
+        platform,
+        index
+    );
+
+    Ok(())
+}
+
+// ============================================================
+// UNLINK IDENTITY
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(platform: String, index: u16)]
+pub struct UnlinkIdentity<'info> {
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, primary_identity.owner.as_ref()],
+        bump = primary_identity.bump
+    )]
+    pub primary_identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, linked_identity.owner.as_ref()],
+        bump = linked_identity.bump
+    )]
+    pub linked_identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            LINKED_IDENTITY_SEED,
+            primary_identity.owner.as_ref(),
+            platform.as_bytes(),
+            &index.to_le_bytes()
+        ],
+        bump = linked_identity_record.bump,
+        constraint = linked_identity_record.linked_address == linked_identity.owner @ KnomeeError::LinkedAddressMismatch
+    )]
+    pub linked_identity_record: Account<'info, LinkedIdentity>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [LINKED_ADDRESS_SEED, linked_identity.owner.as_ref()],
+        bump = linked_address_lookup.bump
+    )]
+    pub linked_address_lookup: Account<'info, LinkedAddressLookup>,
+
+    /// Either the Primary or the linked account may unlink; anyone may clean up a stale link
+    #[account(
+        mut,
+        constraint = authority.key() == primary_identity.owner
+            || authority.key() == linked_identity.owner
+            || !linked_identity.is_linked_to(&primary_identity.owner) @ KnomeeError::UnauthorizedLinkChange
+    )]
+    pub authority: Signer<'info>,
+
+    pub tier_token: TierTokenAccounts<'info>,
+}
+
+pub fn unlink_identity(ctx: Context<UnlinkIdentity>, platform: String, index: u16) -> Result<()> {
+    let primary_identity = &mut ctx.accounts.primary_identity;
+    let linked_identity = &mut ctx.accounts.linked_identity;
+
+    // Only demote while still linked to this Primary; the address may since have
+    // been overturned, re-verified as a Primary or linked elsewhere
+    if linked_identity.is_linked_to(&primary_identity.owner) {
+        linked_identity.tier = IdentityTier::GreyGhost;
+        linked_identity.primary_address = linked_identity.owner;
+        linked_identity.verified_at = 0;
+        ctx.accounts
+            .tier_token
+            .sync(linked_identity, ctx.bumps.tier_token.token_authority)?;
+    } else {
+        msg!("Link is stale, closing records without changing tier");
+    }
+
+    // Decrement linked count on primary
+    primary_identity.linked_count = primary_identity
+        .linked_count
+        .checked_sub(1)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!(
+        "Unlinked identity {} from primary {} on platform: {} (#{})",
         linked_identity.owner,
         primary_identity.owner,
-        platform
+        platform,
+        index
+    );
+
+    Ok(())
+}
+
+// ============================================================
+// RELINK IDENTITY (MOVE TO A DIFFERENT PLATFORM LABEL)
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(old_platform: String, old_index: u16, new_platform: String, new_index: u16)]
+pub struct RelinkIdentity<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        seeds = [IDENTITY_SEED, primary_identity.owner.as_ref()],
+        bump = primary_identity.bump,
+        constraint = primary_identity.is_primary() @ KnomeeError::NotAPrimaryId
+    )]
+    pub primary_identity: Account<'info, Identity>,
+
+    #[account(
+        seeds = [IDENTITY_SEED, linked_identity.owner.as_ref()],
+        bump = linked_identity.bump
+    )]
+    pub linked_identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            LINKED_IDENTITY_SEED,
+            primary_identity.owner.as_ref(),
+            old_platform.as_bytes(),
+            &old_index.to_le_bytes()
+        ],
+        bump = old_linked_identity_record.bump,
+        constraint = old_linked_identity_record.linked_address == linked_identity.owner @ KnomeeError::LinkedAddressMismatch
+    )]
+    pub old_linked_identity_record: Account<'info, LinkedIdentity>,
+
+    #[account(
+        seeds = [PLATFORM_SEED, new_platform.as_bytes()],
+        bump = registered_platform.bump,
+        constraint = registered_platform.enabled @ KnomeeError::PlatformNotRecognized
+    )]
+    pub registered_platform: Account<'info, RegisteredPlatform>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = LinkedIdentity::space(new_platform.len()),
+        seeds = [
+            LINKED_IDENTITY_SEED,
+            primary_identity.owner.as_ref(),
+            new_platform.as_bytes(),
+            &new_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub new_linked_identity_record: Account<'info, LinkedIdentity>,
+
+    #[account(
+        mut,
+        seeds = [LINKED_ADDRESS_SEED, linked_identity.owner.as_ref()],
+        bump = linked_address_lookup.bump
+    )]
+    pub linked_address_lookup: Account<'info, LinkedAddressLookup>,
+
+    /// Either the Primary or the linked account may relink
+    #[account(
+        mut,
+        constraint = authority.key() == primary_identity.owner
+            || authority.key() == linked_identity.owner @ KnomeeError::UnauthorizedLinkChange
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn relink_identity(
+    ctx: Context<RelinkIdentity>,
+    old_platform: String,
+    old_index: u16,
+    new_platform: String,
+    new_index: u16,
+) -> Result<()> {
+    require_canonical_platform(&new_platform)?;
+    require!(new_index < MAX_LINK_INDEX, KnomeeError::InvalidLinkIndex);
+
+    let old_record = &ctx.accounts.old_linked_identity_record;
+    let new_record = &mut ctx.accounts.new_linked_identity_record;
+    let current_time = ctx.accounts.governance.current_time();
+
+    // Move the link to the new platform label (linked_count is unchanged)
+    new_record.primary_address = old_record.primary_address;
+    new_record.linked_address = old_record.linked_address;
+    new_record.platform = new_platform.clone();
+    new_record.index = new_index;
+    new_record.linked_at = current_time;
+    new_record.bump = ctx.bumps.new_linked_identity_record;
+
+    // Point the reverse lookup at the new record
+    let lookup = &mut ctx.accounts.linked_address_lookup;
+    lookup.platform = new_platform.clone();
+    lookup.index = new_index;
+
+    msg!(
+        "Relinked identity {} from platform {} (#{}) to {} (#{})",
+        new_record.linked_address,
+        old_platform,
+        old_index,
+        new_platform,
+        new_index
+    );
+
+    Ok(())
+}
+
+// ============================================================
+// MIGRATE IDENTITY (KEY ROTATION)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct MigrateIdentity<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [IDENTITY_SEED, owner.key().as_ref()],
+        bump = old_identity.bump
+    )]
+    pub old_identity: Account<'info, Identity>,
+
+    /// Target identity, initialized beforehand via `initialize_identity`
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, new_owner.key().as_ref()],
+        bump = new_identity.bump,
+        constraint = new_identity.is_blank() @ KnomeeError::MigrationTargetNotBlank
+    )]
+    pub new_identity: Account<'info, Identity>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = IdentityMigration::LEN,
+        seeds = [MIGRATION_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub migration: Account<'info, IdentityMigration>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// New key must co-sign to prove control
+    pub new_owner: Signer<'info>,
+
+    /// CHECK: Old key's stake account, checked for unsettled vouches and bond (may not exist)
+    #[account(
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Old key's tier token is burned and the new key's minted
+    pub tier_token: TierTokenAccounts<'info>,
+}
+
+pub fn migrate_identity(ctx: Context<MigrateIdentity>) -> Result<()> {
+    let old_identity = &mut ctx.accounts.old_identity;
+    let new_identity = &mut ctx.accounts.new_identity;
+    let migration = &mut ctx.accounts.migration;
+    let current_time = ctx.accounts.governance.current_time();
+
+    require!(
+        ctx.accounts.owner.key() != ctx.accounts.new_owner.key(),
+        KnomeeError::CannotMigrateToSelf
+    );
+    require!(
+        !matches!(old_identity.tier, IdentityTier::LinkedID),
+        KnomeeError::CannotMigrateLinkedIdentity
+    );
+    require!(
+        !old_identity.under_challenge,
+        KnomeeError::AddressUnderChallenge
+    );
+
+    // Claims about the old key resolve against its Identity, so they must settle first
+    require!(
+        !old_identity.has_open_claims(ctx.accounts.governance.params.appeal_window, current_time),
+        KnomeeError::IdentityHasOpenClaims
+    );
+
+    // Settle all vouches and release any Oracle bond first; both need the old Identity
+    require_stake_settled(&ctx.accounts.stake_account, true)?;
+
+    // Move tier and stats to the new key, along with its tier token
+    let authority_bump = ctx.bumps.tier_token.token_authority;
+    ctx.accounts.tier_token.burn(old_identity, authority_bump)?;
+    old_identity.transfer_state_to(new_identity, current_time);
+    ctx.accounts.tier_token.mint(new_identity, authority_bump)?;
+
+    // Revoke the old key
+    migration.old_owner = old_identity.owner;
+    migration.new_owner = new_identity.owner;
+    migration.migrated_at = current_time;
+    migration.recovery_claim_id = 0;
+    migration.bump = ctx.bumps.migration;
+
+    msg!("Identity migrated from {} to {}", migration.old_owner, migration.new_owner);
+    msg!("Linked records to move: {}", new_identity.linked_count);
+
+    Ok(())
+}
+
+// ============================================================
+// MIGRATE LINKED IDENTITY (MOVE RECORD TO MIGRATED PRIMARY)
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(platform: String, index: u16)]
+pub struct MigrateLinkedIdentity<'info> {
+    #[account(
+        seeds = [MIGRATION_SEED, migration.old_owner.as_ref()],
+        bump = migration.bump
+    )]
+    pub migration: Account<'info, IdentityMigration>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, linked_identity.owner.as_ref()],
+        bump = linked_identity.bump,
+        constraint = linked_identity.primary_address == migration.old_owner @ KnomeeError::LinkedAddressMismatch
+    )]
+    pub linked_identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            LINKED_IDENTITY_SEED,
+            migration.old_owner.as_ref(),
+            platform.as_bytes(),
+            &index.to_le_bytes()
+        ],
+        bump = old_linked_identity_record.bump,
+        constraint = old_linked_identity_record.linked_address == linked_identity.owner @ KnomeeError::LinkedAddressMismatch
+    )]
+    pub old_linked_identity_record: Account<'info, LinkedIdentity>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = LinkedIdentity::space(platform.len()),
+        seeds = [
+            LINKED_IDENTITY_SEED,
+            migration.new_owner.as_ref(),
+            platform.as_bytes(),
+            &index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub new_linked_identity_record: Account<'info, LinkedIdentity>,
+
+    #[account(
+        mut,
+        seeds = [LINKED_ADDRESS_SEED, linked_identity.owner.as_ref()],
+        bump = linked_address_lookup.bump
+    )]
+    pub linked_address_lookup: Account<'info, LinkedAddressLookup>,
+
+    /// Anyone may move records once the migration exists
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_linked_identity(
+    ctx: Context<MigrateLinkedIdentity>,
+    platform: String,
+    index: u16,
+) -> Result<()> {
+    let migration = &ctx.accounts.migration;
+    let old_record = &ctx.accounts.old_linked_identity_record;
+    let new_record = &mut ctx.accounts.new_linked_identity_record;
+    let linked_identity = &mut ctx.accounts.linked_identity;
+    let lookup = &mut ctx.accounts.linked_address_lookup;
+
+    // Re-create the record under the new primary (original link time is kept)
+    new_record.primary_address = migration.new_owner;
+    new_record.linked_address = old_record.linked_address;
+    new_record.platform = platform.clone();
+    new_record.index = index;
+    new_record.linked_at = old_record.linked_at;
+    new_record.bump = ctx.bumps.new_linked_identity_record;
+
+    linked_identity.primary_address = migration.new_owner;
+    lookup.primary_address = migration.new_owner;
+
+    msg!(
+        "Moved linked identity {} on platform {} (#{}) to primary {}",
+        linked_identity.owner,
+        platform,
+        index,
+        migration.new_owner
+    );
+
+    Ok(())
+}
+
+// ============================================================
+// EXECUTE RECOVERY (AFTER CONSENSUS APPROVAL)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        constraint = claim.claim_type == ClaimType::Recovery @ KnomeeError::InvalidClaimType,
+        constraint = claim.status == ClaimStatus::Approved @ KnomeeError::RecoveryNotExecutable,
+        constraint = claim.subject == new_owner.key() @ KnomeeError::SubjectAddressMismatch
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    /// Identity of the lost key (rent goes to the recovered owner)
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [IDENTITY_SEED, claim.related_address.as_ref()],
+        bump = old_identity.bump
+    )]
+    pub old_identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, new_owner.key().as_ref()],
+        bump = new_identity.bump,
+        constraint = new_identity.is_blank() @ KnomeeError::MigrationTargetNotBlank
+    )]
+    pub new_identity: Account<'info, Identity>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = IdentityMigration::LEN,
+        seeds = [MIGRATION_SEED, claim.related_address.as_ref()],
+        bump
+    )]
+    pub migration: Account<'info, IdentityMigration>,
+
+    /// CHECK: Lost key's stake account, checked for unsettled vouches; its Oracle bond
+    /// moves to the new owner (may not exist)
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, claim.related_address.as_ref()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// New owner's stake account (required when the lost key has an Oracle bond)
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, new_owner.key().as_ref()],
+        bump = new_stake_account.bump
+    )]
+    pub new_stake_account: Option<Account<'info, StakeAccount>>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Lost key's tier token is burned and the recovered key's minted
+    pub tier_token: TierTokenAccounts<'info>,
+}
+
+pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let claim = &ctx.accounts.claim;
+    let old_identity = &mut ctx.accounts.old_identity;
+    let new_identity = &mut ctx.accounts.new_identity;
+    let migration = &mut ctx.accounts.migration;
+    let current_time = governance.current_time();
+
+    // Give the old owner the full appeal window to contest, and wait out any appeal
+    require!(
+        claim.is_final(governance.params.appeal_window, current_time),
+        KnomeeError::RecoveryNotExecutable
+    );
+
+    // A duplicate challenge opened after approval must be resolved first
+    require!(
+        !old_identity.under_challenge,
+        KnomeeError::AddressUnderChallenge
+    );
+
+    // As must any other claim about the lost key, which resolves against its Identity
+    require!(
+        !old_identity.has_open_claims(governance.params.appeal_window, current_time),
+        KnomeeError::IdentityHasOpenClaims
+    );
+
+    // Vouches from the lost key must be settled (anyone can crank them) before its Identity closes
+    require_stake_settled(&ctx.accounts.stake_account, false)?;
+
+    // Move tier and stats to the new key, along with its Oracle bond and tier token
+    let authority_bump = ctx.bumps.tier_token.token_authority;
+    ctx.accounts.tier_token.burn(old_identity, authority_bump)?;
+    old_identity.transfer_state_to(new_identity, current_time);
+    let bond = move_oracle_bond(
+        &ctx.accounts.stake_account,
+        ctx.accounts.new_stake_account.as_deref_mut(),
+    )?;
+    if new_identity.is_oracle() && bond < governance.params.min_oracle_bond {
+        new_identity.tier = IdentityTier::PrimaryID;
+        msg!("Recovered Oracle demoted: bond {} below minimum", bond);
+    }
+    ctx.accounts.tier_token.mint(new_identity, authority_bump)?;
+
+    // Revoke the lost key
+    migration.old_owner = old_identity.owner;
+    migration.new_owner = new_identity.owner;
+    migration.migrated_at = current_time;
+    migration.recovery_claim_id = claim.claim_id;
+    migration.bump = ctx.bumps.migration;
+
+    msg!(
+        "Identity recovered from {} to {} via claim {}",
+        migration.old_owner,
+        migration.new_owner,
+        claim.claim_id
     );
 
     Ok(())
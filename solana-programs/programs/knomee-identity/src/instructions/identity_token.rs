@@ -0,0 +1,346 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{self, extension::ExtensionType},
+    Burn, InitializeMint2, MintTo, Token2022,
+};
+use anchor_spl::token_interface::TokenAccount;
+use crate::{constants::*, errors::KnomeeError, state::*};
+
+// ============================================================
+// INITIALIZE TIER MINT (GOVERNANCE ONLY)
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(tier: IdentityTier)]
+pub struct InitializeTierMint<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    /// Soul-bound mint for this tier
+    /// CHECK: Created and initialized as a Token-2022 mint in the handler
+    #[account(
+        mut,
+        seeds = [TIER_MINT_SEED, &[tier.to_u8()]],
+        bump
+    )]
+    pub tier_mint: UncheckedAccount<'info>,
+
+    /// Mint, freeze and permanent delegate authority for identity tokens
+    /// CHECK: PDA signer only
+    #[account(
+        seeds = [IDENTITY_TOKEN_AUTHORITY_SEED],
+        bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_tier_mint(ctx: Context<InitializeTierMint>, tier: IdentityTier) -> Result<()> {
+    require!(tier.has_identity_token(), KnomeeError::InvalidIdentityTier);
+
+    let token_program_id = ctx.accounts.token_program.key();
+    let mint_key = ctx.accounts.tier_mint.key();
+    let authority_key = ctx.accounts.token_authority.key();
+
+    // Non-transferable (soul-bound) with the program as permanent delegate so it can burn on downgrade
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::PermanentDelegate,
+    ])?;
+    let lamports = Rent::get()?.minimum_balance(space);
+
+    let tier_seed = [tier.to_u8()];
+    let mint_seeds: &[&[u8]] = &[TIER_MINT_SEED, &tier_seed, &[ctx.bumps.tier_mint]];
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let tier_mint = ctx.accounts.tier_mint.to_account_info();
+    let current_lamports = tier_mint.lamports();
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: tier_mint,
+                },
+                &[mint_seeds],
+            ),
+            lamports,
+            space as u64,
+            &token_program_id,
+        )?;
+    } else {
+        // Anyone can send lamports to the PDA, which makes `create_account` fail; top up
+        // to rent exemption and allocate/assign it instead
+        let top_up = lamports.saturating_sub(current_lamports);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: tier_mint.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate {
+                    account_to_allocate: tier_mint.clone(),
+                },
+                &[mint_seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program,
+                Assign {
+                    account_to_assign: tier_mint,
+                },
+                &[mint_seeds],
+            ),
+            &token_program_id,
+        )?;
+    }
+
+    invoke(
+        &spl_token_2022::instruction::initialize_non_transferable_mint(&token_program_id, &mint_key)?,
+        &[ctx.accounts.tier_mint.to_account_info()],
+    )?;
+    invoke(
+        &spl_token_2022::instruction::initialize_permanent_delegate(
+            &token_program_id,
+            &mint_key,
+            &authority_key,
+        )?,
+        &[ctx.accounts.tier_mint.to_account_info()],
+    )?;
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.tier_mint.to_account_info(),
+            },
+        ),
+        0,
+        &authority_key,
+        Some(&authority_key),
+    )?;
+
+    msg!("Identity token mint for tier {} created: {}", tier.to_u8(), mint_key);
+
+    Ok(())
+}
+
+// ============================================================
+// TIER TOKEN ACCOUNTS
+// ============================================================
+
+/// Token-2022 accounts used to keep an identity's soul-bound token in step with its tier.
+/// Embedded in every instruction that changes `Identity.tier` or closes an Identity.
+#[derive(Accounts)]
+pub struct TierTokenAccounts<'info> {
+    /// CHECK: PDA signer only
+    #[account(
+        seeds = [IDENTITY_TOKEN_AUTHORITY_SEED],
+        bump
+    )]
+    pub token_authority: UncheckedAccount<'info>,
+
+    /// Mint of the tier token currently held (required if one is held)
+    /// CHECK: Address checked against the tier mint PDA before burning
+    #[account(mut)]
+    pub old_tier_mint: Option<UncheckedAccount<'info>>,
+
+    /// Holder's token account for the old tier mint
+    #[account(mut)]
+    pub old_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Mint of the identity's new tier (required unless the tier is GreyGhost)
+    /// CHECK: Address checked against the tier mint PDA; minting is skipped until it is initialized
+    #[account(mut)]
+    pub new_tier_mint: Option<UncheckedAccount<'info>>,
+
+    /// Holder's token account for the new tier mint (e.g. its Token-2022 ATA)
+    #[account(mut)]
+    pub new_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> TierTokenAccounts<'info> {
+    /// Burn the held tier token and mint one for the identity's current tier
+    pub fn sync(&self, identity: &mut Identity, authority_bump: u8) -> Result<()> {
+        if identity.token_tier == identity.tier {
+            return Ok(());
+        }
+        self.burn(identity, authority_bump)?;
+        self.mint(identity, authority_bump)
+    }
+
+    /// Burn the tier token held by `identity`, if any
+    pub fn burn(&self, identity: &mut Identity, authority_bump: u8) -> Result<()> {
+        if !identity.token_tier.has_identity_token() {
+            return Ok(());
+        }
+
+        let mint = self
+            .old_tier_mint
+            .as_ref()
+            .ok_or(KnomeeError::IdentityTokenAccountMismatch)?;
+        let token_account = self
+            .old_token_account
+            .as_ref()
+            .ok_or(KnomeeError::IdentityTokenAccountMismatch)?;
+        require_tier_token_accounts(identity, identity.token_tier, mint, token_account)?;
+
+        if token_account.amount > 0 {
+            token_2022::burn(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Burn {
+                        mint: mint.to_account_info(),
+                        from: token_account.to_account_info(),
+                        authority: self.token_authority.to_account_info(),
+                    },
+                    &[&[IDENTITY_TOKEN_AUTHORITY_SEED, &[authority_bump]]],
+                ),
+                token_account.amount,
+            )?;
+        }
+
+        msg!("Tier {} token burned for {}", identity.token_tier.to_u8(), identity.owner);
+        identity.token_tier = IdentityTier::GreyGhost;
+
+        Ok(())
+    }
+
+    /// Mint a token for the identity's current tier once that tier's mint exists
+    pub fn mint(&self, identity: &mut Identity, authority_bump: u8) -> Result<()> {
+        if !identity.tier.has_identity_token() {
+            return Ok(());
+        }
+
+        let mint = self
+            .new_tier_mint
+            .as_ref()
+            .ok_or(KnomeeError::IdentityTokenAccountMismatch)?;
+        require_keys_eq!(
+            mint.key(),
+            tier_mint_address(identity.tier),
+            KnomeeError::IdentityTokenAccountMismatch
+        );
+
+        // Tier mints are created by governance; until then `sync_identity_token` catches up later
+        if mint.data_is_empty() {
+            msg!("Tier {} mint not initialized, token not minted", identity.tier.to_u8());
+            return Ok(());
+        }
+
+        let token_account = self
+            .new_token_account
+            .as_ref()
+            .ok_or(KnomeeError::IdentityTokenAccountMismatch)?;
+        require_tier_token_accounts(identity, identity.tier, mint, token_account)?;
+
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: mint.to_account_info(),
+                    to: token_account.to_account_info(),
+                    authority: self.token_authority.to_account_info(),
+                },
+                &[&[IDENTITY_TOKEN_AUTHORITY_SEED, &[authority_bump]]],
+            ),
+            1,
+        )?;
+
+        msg!("Tier {} token minted for {}", identity.tier.to_u8(), identity.owner);
+        identity.token_tier = identity.tier;
+
+        Ok(())
+    }
+}
+
+fn tier_mint_address(tier: IdentityTier) -> Pubkey {
+    Pubkey::find_program_address(&[TIER_MINT_SEED, &[tier.to_u8()]], &crate::ID).0
+}
+
+fn require_tier_token_accounts(
+    identity: &Identity,
+    tier: IdentityTier,
+    mint: &UncheckedAccount,
+    token_account: &InterfaceAccount<TokenAccount>,
+) -> Result<()> {
+    require_keys_eq!(
+        mint.key(),
+        tier_mint_address(tier),
+        KnomeeError::IdentityTokenAccountMismatch
+    );
+    require_keys_eq!(
+        token_account.mint,
+        mint.key(),
+        KnomeeError::IdentityTokenAccountMismatch
+    );
+    require_keys_eq!(
+        token_account.owner,
+        identity.owner,
+        KnomeeError::IdentityTokenAccountMismatch
+    );
+    Ok(())
+}
+
+// ============================================================
+// SYNC IDENTITY TOKEN
+// ============================================================
+
+#[derive(Accounts)]
+pub struct SyncIdentityToken<'info> {
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, identity.owner.as_ref()],
+        bump = identity.bump
+    )]
+    pub identity: Account<'info, Identity>,
+
+    pub tier_token: TierTokenAccounts<'info>,
+}
+
+/// Burn the token for the previously held tier and mint one for the current tier.
+/// Permissionless catch-up for identities whose tier changed before its tier mint existed.
+pub fn sync_identity_token(ctx: Context<SyncIdentityToken>) -> Result<()> {
+    let identity = &mut ctx.accounts.identity;
+
+    require!(
+        identity.token_tier != identity.tier,
+        KnomeeError::IdentityTokenInSync
+    );
+
+    ctx.accounts
+        .tier_token
+        .sync(identity, ctx.bumps.tier_token.token_authority)?;
+
+    msg!(
+        "Identity token synced for {}: tier {}",
+        identity.owner,
+        identity.token_tier.to_u8()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::slot_hashes};
+use crate::{constants::*, errors::KnomeeError, state::*};
+
+// ============================================================
+// DRAW JURY
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct DrawJury<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.status == ClaimStatus::Active @ KnomeeError::ClaimAlreadyResolved,
+        constraint = claim.jury_selection_bps > 0 @ KnomeeError::JuryNotRequired
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Jury::LEN,
+        seeds = [JURY_SEED, &claim_id.to_le_bytes()],
+        bump
+    )]
+    pub jury: Account<'info, Jury>,
+
+    /// CHECK: SlotHashes sysvar, read manually to avoid deserializing all entries
+    #[account(address = slot_hashes::ID)]
+    pub recent_slothashes: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: draw the jury from the hash of a fixed slot shortly after claim creation.
+/// The source slot is unknown when the claim is created and cannot be chosen by the caller.
+/// If that slot was skipped or has aged out of SlotHashes the draw is rejected, and
+/// `reanchor_jury_draw` moves it to a future slot instead.
+pub fn draw_jury(ctx: Context<DrawJury>, claim_id: u64) -> Result<()> {
+    let claim = &ctx.accounts.claim;
+    let jury = &mut ctx.accounts.jury;
+
+    let source_slot = jury_source_slot(claim)?;
+    let slot_hash = find_slot_hash(&ctx.accounts.recent_slothashes, source_slot)?
+        .ok_or(KnomeeError::JurySlotUnavailable)?;
+
+    jury.claim_id = claim_id;
+    jury.seed = hashv(&[&slot_hash, &claim_id.to_le_bytes()]).to_bytes();
+    jury.selection_bps = claim.jury_selection_bps;
+    jury.source_slot = source_slot;
+    jury.drawn_at = ctx.accounts.governance.current_time();
+    jury.bump = ctx.bumps.jury;
+
+    msg!("Jury drawn for claim {} from slot {}", claim_id, source_slot);
+    msg!("Selection rate: {} bps", jury.selection_bps);
+
+    Ok(())
+}
+
+// ============================================================
+// REANCHOR JURY DRAW
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct ReanchorJuryDraw<'info> {
+    #[account(
+        mut,
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus,
+        constraint = claim.status == ClaimStatus::Active @ KnomeeError::ClaimAlreadyResolved,
+        constraint = claim.jury_selection_bps > 0 @ KnomeeError::JuryNotRequired
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    /// CHECK: Jury PDA, which must not have been drawn yet
+    #[account(
+        seeds = [JURY_SEED, &claim_id.to_le_bytes()],
+        bump,
+        constraint = jury.data_is_empty() @ KnomeeError::JuryAlreadyDrawn
+    )]
+    pub jury: UncheckedAccount<'info>,
+
+    /// CHECK: SlotHashes sysvar, read manually to avoid deserializing all entries
+    #[account(address = slot_hashes::ID)]
+    pub recent_slothashes: UncheckedAccount<'info>,
+}
+
+/// Permissionless: when a claim's jury source slot has passed without a hash in SlotHashes
+/// (skipped or aged out), anchor the draw to the current slot so it uses a future slot
+pub fn reanchor_jury_draw(ctx: Context<ReanchorJuryDraw>, claim_id: u64) -> Result<()> {
+    let claim = &mut ctx.accounts.claim;
+    let current_slot = Clock::get()?.slot;
+    let source_slot = jury_source_slot(claim)?;
+
+    require!(
+        current_slot > source_slot
+            && find_slot_hash(&ctx.accounts.recent_slothashes, source_slot)?.is_none(),
+        KnomeeError::JurySlotAvailable
+    );
+
+    claim.created_slot = current_slot;
+
+    msg!(
+        "Jury draw for claim {} moved from slot {} to slot {}",
+        claim_id,
+        source_slot,
+        jury_source_slot(claim)?
+    );
+
+    Ok(())
+}
+
+// ============================================================
+// HELPERS
+// ============================================================
+
+/// Slot whose hash seeds the claim's jury
+fn jury_source_slot(claim: &IdentityClaim) -> Result<u64> {
+    Ok(claim
+        .created_slot
+        .checked_add(JURY_DRAW_DELAY_SLOTS)
+        .ok_or(KnomeeError::ArithmeticOverflow)?)
+}
+
+/// Hash of `slot` in the SlotHashes sysvar (u64 length, then (slot, hash) entries,
+/// newest first), or None if it is not there
+fn find_slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    let data = slot_hashes.try_borrow_data()?;
+    let len = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+
+    for i in 0..len {
+        let offset = 8 + i * 40;
+        if offset + 40 > data.len() {
+            break;
+        }
+        let entry_slot = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        if entry_slot < slot {
+            break;
+        }
+        if entry_slot == slot {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&data[offset + 8..offset + 40]);
+            return Ok(Some(hash));
+        }
+    }
+
+    Ok(None)
+}
@@ -1,7 +1,25 @@
 pub mod governance;
 pub mod identity;
 pub mod consensus;
+pub mod recovery;
+pub mod identity_token;
+pub mod rewards;
+pub mod ubi;
+pub mod treasury;
+pub mod stake;
+pub mod delegation;
+pub mod jury;
+pub mod evidence;
 
 pub use governance::*;
 pub use identity::*;
 pub use consensus::*;
+pub use recovery::*;
+pub use identity_token::*;
+pub use rewards::*;
+pub use ubi::*;
+pub use treasury::*;
+pub use stake::*;
+pub use delegation::*;
+pub use jury::*;
+pub use evidence::*;
@@ -0,0 +1,356 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::KnomeeError,
+    instructions::{
+        identity_token::*,
+        stake::{move_oracle_bond, require_stake_settled},
+    },
+    state::*,
+};
+
+// ============================================================
+// CONFIGURE GUARDIAN RECOVERY
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ConfigureRecovery<'info> {
+    #[account(
+        seeds = [IDENTITY_SEED, owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.is_primary() @ KnomeeError::NotAPrimaryId
+    )]
+    pub identity: Account<'info, Identity>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = RecoveryConfig::LEN,
+        seeds = [RECOVERY_CONFIG_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_recovery(
+    ctx: Context<ConfigureRecovery>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+    veto_delay: i64,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    validate_guardians(&owner, &guardians, threshold, veto_delay)?;
+
+    let config = &mut ctx.accounts.recovery_config;
+    config.owner = owner;
+    config.guardians = guardians;
+    config.threshold = threshold;
+    config.veto_delay = veto_delay;
+    config.clear_proposal();
+    config.bump = ctx.bumps.recovery_config;
+
+    msg!("Recovery configured for: {}", owner);
+    msg!("Guardians: {}, threshold: {}", config.guardians.len(), threshold);
+
+    Ok(())
+}
+
+// ============================================================
+// UPDATE GUARDIAN RECOVERY
+// ============================================================
+
+#[derive(Accounts)]
+pub struct UpdateRecoveryConfig<'info> {
+    #[account(
+        mut,
+        seeds = [RECOVERY_CONFIG_SEED, owner.key().as_ref()],
+        bump = recovery_config.bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn update_recovery_config(
+    ctx: Context<UpdateRecoveryConfig>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+    veto_delay: i64,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    validate_guardians(&owner, &guardians, threshold, veto_delay)?;
+
+    // Changing guardians discards any pending proposal
+    let config = &mut ctx.accounts.recovery_config;
+    config.guardians = guardians;
+    config.threshold = threshold;
+    config.veto_delay = veto_delay;
+    config.clear_proposal();
+
+    msg!("Recovery config updated for: {}", owner);
+
+    Ok(())
+}
+
+fn validate_guardians(
+    owner: &Pubkey,
+    guardians: &[Pubkey],
+    threshold: u8,
+    veto_delay: i64,
+) -> Result<()> {
+    require!(
+        !guardians.is_empty() && guardians.len() <= MAX_GUARDIANS,
+        KnomeeError::InvalidGuardianSet
+    );
+    require!(
+        threshold > 0 && threshold as usize <= guardians.len(),
+        KnomeeError::InvalidGuardianThreshold
+    );
+    require!(
+        veto_delay >= MIN_GUARDIAN_VETO_DELAY,
+        KnomeeError::GuardianVetoDelayTooShort
+    );
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(
+            guardian != owner && *guardian != Pubkey::default() && !guardians[..i].contains(guardian),
+            KnomeeError::InvalidGuardianSet
+        );
+    }
+    Ok(())
+}
+
+// ============================================================
+// APPROVE GUARDIAN RECOVERY
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ApproveGuardianRecovery<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_CONFIG_SEED, recovery_config.owner.as_ref()],
+        bump = recovery_config.bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn approve_guardian_recovery(
+    ctx: Context<ApproveGuardianRecovery>,
+    new_owner: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.recovery_config;
+    let current_time = ctx.accounts.governance.current_time();
+
+    let index = config
+        .guardian_index(&ctx.accounts.guardian.key())
+        .ok_or(KnomeeError::NotAGuardian)?;
+
+    require!(
+        new_owner != Pubkey::default() && new_owner != config.owner,
+        KnomeeError::CannotMigrateToSelf
+    );
+
+    // A proposal that never reached the threshold expires and can be replaced
+    if config.is_proposal_stale(current_time) {
+        msg!("Replacing stale recovery proposal for {}", config.proposed_owner);
+        config.clear_proposal();
+    }
+
+    // First approval opens the proposal; later approvals must agree on the new owner
+    if !config.has_pending_proposal() {
+        config.proposed_owner = new_owner;
+        config.proposed_at = current_time;
+    } else {
+        require!(
+            config.proposed_owner == new_owner,
+            KnomeeError::RecoveryProposalMismatch
+        );
+    }
+
+    config.approvals |= 1 << index;
+
+    // Start the veto window once the threshold is first reached
+    if config.approved_at == 0 && config.approval_count() >= config.threshold {
+        config.approved_at = current_time;
+        msg!("Recovery threshold reached, veto window ends at {}", current_time + config.veto_delay);
+    }
+
+    msg!(
+        "Guardian {} approved recovery of {} to {} ({}/{})",
+        ctx.accounts.guardian.key(),
+        config.owner,
+        new_owner,
+        config.approval_count(),
+        config.threshold
+    );
+
+    Ok(())
+}
+
+// ============================================================
+// VETO GUARDIAN RECOVERY
+// ============================================================
+
+#[derive(Accounts)]
+pub struct VetoGuardianRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [RECOVERY_CONFIG_SEED, owner.key().as_ref()],
+        bump = recovery_config.bump
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn veto_guardian_recovery(ctx: Context<VetoGuardianRecovery>) -> Result<()> {
+    let config = &mut ctx.accounts.recovery_config;
+
+    require!(config.has_pending_proposal(), KnomeeError::NoPendingRecovery);
+
+    msg!("Owner {} vetoed recovery to {}", config.owner, config.proposed_owner);
+
+    config.clear_proposal();
+
+    Ok(())
+}
+
+// ============================================================
+// EXECUTE GUARDIAN RECOVERY
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ExecuteGuardianRecovery<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [RECOVERY_CONFIG_SEED, recovery_config.owner.as_ref()],
+        bump = recovery_config.bump,
+        constraint = recovery_config.proposed_owner == new_owner.key() @ KnomeeError::RecoveryProposalMismatch
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [IDENTITY_SEED, recovery_config.owner.as_ref()],
+        bump = old_identity.bump
+    )]
+    pub old_identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, new_owner.key().as_ref()],
+        bump = new_identity.bump,
+        constraint = new_identity.is_blank() @ KnomeeError::MigrationTargetNotBlank
+    )]
+    pub new_identity: Account<'info, Identity>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = IdentityMigration::LEN,
+        seeds = [MIGRATION_SEED, recovery_config.owner.as_ref()],
+        bump
+    )]
+    pub migration: Account<'info, IdentityMigration>,
+
+    /// CHECK: Lost key's stake account, checked for unsettled vouches; its Oracle bond
+    /// moves to the new owner (may not exist)
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, recovery_config.owner.as_ref()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// New owner's stake account (required when the lost key has an Oracle bond)
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, new_owner.key().as_ref()],
+        bump = new_stake_account.bump
+    )]
+    pub new_stake_account: Option<Account<'info, StakeAccount>>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Lost key's tier token is burned and the recovered key's minted
+    pub tier_token: TierTokenAccounts<'info>,
+}
+
+pub fn execute_guardian_recovery(ctx: Context<ExecuteGuardianRecovery>) -> Result<()> {
+    let config = &ctx.accounts.recovery_config;
+    let old_identity = &mut ctx.accounts.old_identity;
+    let new_identity = &mut ctx.accounts.new_identity;
+    let migration = &mut ctx.accounts.migration;
+    let params = &ctx.accounts.governance.params;
+    let current_time = ctx.accounts.governance.current_time();
+
+    require!(
+        config.is_executable(current_time),
+        KnomeeError::RecoveryNotExecutable
+    );
+    require!(
+        !old_identity.under_challenge,
+        KnomeeError::AddressUnderChallenge
+    );
+
+    // Claims about the lost key resolve against its Identity, so they must settle first
+    require!(
+        !old_identity.has_open_claims(params.appeal_window, current_time),
+        KnomeeError::IdentityHasOpenClaims
+    );
+
+    // Vouches from the lost key must be settled (anyone can crank them) before its Identity closes
+    require_stake_settled(&ctx.accounts.stake_account, false)?;
+
+    // Move tier and stats to the new key, along with its Oracle bond and tier token
+    let authority_bump = ctx.bumps.tier_token.token_authority;
+    ctx.accounts.tier_token.burn(old_identity, authority_bump)?;
+    old_identity.transfer_state_to(new_identity, current_time);
+    let bond = move_oracle_bond(
+        &ctx.accounts.stake_account,
+        ctx.accounts.new_stake_account.as_deref_mut(),
+    )?;
+    if new_identity.is_oracle() && bond < params.min_oracle_bond {
+        new_identity.tier = IdentityTier::PrimaryID;
+        msg!("Recovered Oracle demoted: bond {} below minimum", bond);
+    }
+    ctx.accounts.tier_token.mint(new_identity, authority_bump)?;
+
+    // Revoke the old key
+    migration.old_owner = old_identity.owner;
+    migration.new_owner = new_identity.owner;
+    migration.migrated_at = current_time;
+    migration.recovery_claim_id = 0;
+    migration.bump = ctx.bumps.migration;
+
+    msg!(
+        "Identity recovered by guardians from {} to {}",
+        migration.old_owner,
+        migration.new_owner
+    );
+
+    Ok(())
+}
@@ -0,0 +1,344 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::KnomeeError, state::*};
+
+// ============================================================
+// INITIALIZE REWARDS POOL (GOVERNANCE ONLY)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct InitializeRewardsPool<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RewardsPool::LEN,
+        seeds = [REWARDS_POOL_SEED],
+        bump
+    )]
+    pub rewards_pool: Account<'info, RewardsPool>,
+
+    /// KNOW mint whose mint authority has been set to the rewards pool PDA
+    pub know_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [REWARDS_VAULT_SEED],
+        bump,
+        token::mint = know_mint,
+        token::authority = rewards_pool
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_rewards_pool(ctx: Context<InitializeRewardsPool>) -> Result<()> {
+    let rewards_pool = &mut ctx.accounts.rewards_pool;
+
+    require!(
+        ctx.accounts.know_mint.mint_authority == COption::Some(rewards_pool.key()),
+        KnomeeError::InvalidKnowMint
+    );
+
+    rewards_pool.know_mint = ctx.accounts.know_mint.key();
+    rewards_pool.vault = ctx.accounts.rewards_vault.key();
+    rewards_pool.launched_at = ctx.accounts.governance.current_time();
+    rewards_pool.total_funded = 0;
+    rewards_pool.total_rewards_paid = 0;
+    rewards_pool.bump = ctx.bumps.rewards_pool;
+
+    msg!("Rewards pool initialized for KNOW mint {}", rewards_pool.know_mint);
+    msg!("Early adopter period ends at {}", rewards_pool.launched_at + EARLY_ADOPTER_PERIOD);
+
+    Ok(())
+}
+
+// ============================================================
+// FUND REWARDS POOL (GOVERNANCE ONLY)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct FundRewardsPool<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [REWARDS_POOL_SEED],
+        bump = rewards_pool.bump,
+        has_one = know_mint @ KnomeeError::InvalidKnowMint
+    )]
+    pub rewards_pool: Account<'info, RewardsPool>,
+
+    #[account(mut)]
+    pub know_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        address = rewards_pool.vault
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Mint new KNOW into the rewards vault, up to the maximum supply
+pub fn fund_rewards_pool(ctx: Context<FundRewardsPool>, amount: u64) -> Result<()> {
+    let rewards_pool = &mut ctx.accounts.rewards_pool;
+
+    let new_supply = ctx
+        .accounts
+        .know_mint
+        .supply
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    require!(new_supply <= MAX_KNOW_SUPPLY, KnomeeError::MaxSupplyExceeded);
+
+    let signer_seeds: &[&[u8]] = &[REWARDS_POOL_SEED, &[rewards_pool.bump]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.know_mint.to_account_info(),
+                to: ctx.accounts.rewards_vault.to_account_info(),
+                authority: rewards_pool.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )?;
+
+    rewards_pool.total_funded = rewards_pool
+        .total_funded
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!("Rewards pool funded with {} KNOW", amount);
+
+    Ok(())
+}
+
+// ============================================================
+// CLAIM PRIMARY ID REWARD
+// ============================================================
+
+#[derive(Accounts)]
+pub struct ClaimPrimaryReward<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [REWARDS_POOL_SEED],
+        bump = rewards_pool.bump
+    )]
+    pub rewards_pool: Account<'info, RewardsPool>,
+
+    #[account(
+        mut,
+        address = rewards_pool.vault
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.is_primary() @ KnomeeError::NotAPrimaryId,
+        constraint = !identity.primary_reward_claimed @ KnomeeError::PrimaryRewardAlreadyClaimed
+    )]
+    pub identity: Account<'info, Identity>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == rewards_pool.know_mint @ KnomeeError::InvalidKnowMint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// One-time KNOW bonus for a verified Primary ID
+pub fn claim_primary_reward(ctx: Context<ClaimPrimaryReward>) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let current_time = governance.current_time();
+
+    // The approval could still be appealed and overturned
+    require!(
+        !ctx.accounts
+            .identity
+            .has_open_claims(governance.params.appeal_window, current_time),
+        KnomeeError::ClaimNotFinal
+    );
+
+    let reward = ctx
+        .accounts
+        .rewards_pool
+        .reward_amount(PRIMARY_ID_REWARD, current_time)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    pay_reward(
+        &mut ctx.accounts.rewards_pool,
+        &ctx.accounts.rewards_vault,
+        &ctx.accounts.owner_token_account,
+        &ctx.accounts.token_program,
+        reward,
+    )?;
+
+    ctx.accounts.identity.primary_reward_claimed = true;
+
+    msg!("Primary ID reward paid: {} KNOW", reward);
+
+    Ok(())
+}
+
+// ============================================================
+// CLAIM VOTING REWARD
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct ClaimVotingReward<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [REWARDS_POOL_SEED],
+        bump = rewards_pool.bump
+    )]
+    pub rewards_pool: Account<'info, RewardsPool>,
+
+    #[account(
+        mut,
+        address = rewards_pool.vault
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = claim.claim_id == claim_id @ KnomeeError::InvalidClaimStatus
+    )]
+    pub claim: Account<'info, IdentityClaim>,
+
+    #[account(
+        mut,
+        seeds = [VOUCH_SEED, &claim_id.to_le_bytes(), voucher.key().as_ref()],
+        bump = vouch.bump,
+        constraint = !vouch.know_reward_paid @ KnomeeError::RewardsAlreadyClaimed
+    )]
+    pub vouch: Account<'info, Vouch>,
+
+    pub voucher: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = voucher_token_account.mint == rewards_pool.know_mint @ KnomeeError::InvalidKnowMint
+    )]
+    pub voucher_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// KNOW reward for voting with the winning side; Oracles (at vote time) also receive the per-claim Oracle reward
+pub fn claim_voting_reward(ctx: Context<ClaimVotingReward>, _claim_id: u64) -> Result<()> {
+    let claim = &ctx.accounts.claim;
+    let vouch = &ctx.accounts.vouch;
+    let governance = &ctx.accounts.governance;
+
+    // An appeal could still flip the winning side
+    require!(
+        claim.is_final(governance.params.appeal_window, governance.current_time()),
+        KnomeeError::ClaimNotFinal
+    );
+
+    // Winners earn the base reward, plus the Oracle reward if they voted as an Oracle
+    let base_reward = vouch.voting_reward(claim, &governance.params);
+    require!(base_reward > 0, KnomeeError::NoVotingReward);
+
+    let current_time = governance.current_time();
+    let reward = ctx
+        .accounts
+        .rewards_pool
+        .reward_amount(base_reward, current_time)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    pay_reward(
+        &mut ctx.accounts.rewards_pool,
+        &ctx.accounts.rewards_vault,
+        &ctx.accounts.voucher_token_account,
+        &ctx.accounts.token_program,
+        reward,
+    )?;
+
+    ctx.accounts.vouch.know_reward_paid = true;
+
+    msg!("Voting reward paid: {} KNOW", reward);
+
+    Ok(())
+}
+
+// ============================================================
+// HELPERS
+// ============================================================
+
+/// Transfer `amount` KNOW from the rewards vault, signed by the pool PDA
+fn pay_reward<'info>(
+    rewards_pool: &mut Account<'info, RewardsPool>,
+    rewards_vault: &Account<'info, TokenAccount>,
+    recipient: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        rewards_vault.amount >= amount,
+        KnomeeError::InsufficientRewardsPool
+    );
+
+    let signer_seeds: &[&[u8]] = &[REWARDS_POOL_SEED, &[rewards_pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: rewards_vault.to_account_info(),
+                to: recipient.to_account_info(),
+                authority: rewards_pool.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )?;
+
+    rewards_pool.total_rewards_paid = rewards_pool
+        .total_rewards_paid
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    Ok(())
+}
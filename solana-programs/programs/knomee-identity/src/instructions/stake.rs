@@ -0,0 +1,403 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::KnomeeError, instructions::identity_token::*, state::*};
+
+// ============================================================
+// INITIALIZE STAKE ACCOUNT
+// ============================================================
+
+#[derive(Accounts)]
+pub struct InitializeStakeAccount<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = StakeAccount::LEN,
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_stake_account(ctx: Context<InitializeStakeAccount>) -> Result<()> {
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    stake_account.owner = ctx.accounts.owner.key();
+    stake_account.deposited = 0;
+    stake_account.locked = 0;
+    stake_account.unbonding = 0;
+    stake_account.bonded = 0;
+    stake_account.unbonding_available_at = 0;
+    stake_account.active_vouches = 0;
+    stake_account.bump = ctx.bumps.stake_account;
+
+    msg!("Stake account initialized for {}", stake_account.owner);
+
+    Ok(())
+}
+
+// ============================================================
+// DEPOSIT STAKE
+// ============================================================
+
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn deposit_stake(ctx: Context<DepositStake>, amount: u64) -> Result<()> {
+    require!(amount > 0, KnomeeError::InsufficientStake);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.stake_escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.deposited = stake_account
+        .deposited
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!("Deposited {} KNOW, balance: {}", amount, stake_account.deposited);
+
+    Ok(())
+}
+
+// ============================================================
+// REQUEST UNSTAKE
+// ============================================================
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Start unbonding unlocked KNOW (restarts the timer for any amount already unbonding)
+pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let current_time = governance.current_time();
+
+    require!(amount > 0, KnomeeError::InsufficientStake);
+    require!(
+        amount <= stake_account.available(),
+        KnomeeError::InsufficientAvailableStake
+    );
+
+    stake_account.unbonding = stake_account
+        .unbonding
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    stake_account.unbonding_available_at = current_time
+        .checked_add(governance.params.stake_unbonding_period)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!(
+        "Unbonding {} KNOW, withdrawable at {}",
+        stake_account.unbonding,
+        stake_account.unbonding_available_at
+    );
+
+    Ok(())
+}
+
+// ============================================================
+// WITHDRAW STAKE
+// ============================================================
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ESCROW_SEED],
+        bump
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw all unbonded KNOW once the unbonding period has passed
+pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+    let current_time = ctx.accounts.governance.current_time();
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    require!(stake_account.unbonding > 0, KnomeeError::NothingToWithdraw);
+    require!(
+        current_time >= stake_account.unbonding_available_at,
+        KnomeeError::StakeStillUnbonding
+    );
+
+    let amount = stake_account.unbonding;
+    let escrow_seeds: &[&[u8]] = &[STAKE_ESCROW_SEED, &[ctx.bumps.stake_escrow]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_escrow.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.stake_escrow.to_account_info(),
+            },
+            &[escrow_seeds],
+        ),
+        amount,
+    )?;
+
+    stake_account.deposited = stake_account
+        .deposited
+        .checked_sub(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    stake_account.unbonding = 0;
+    stake_account.unbonding_available_at = 0;
+
+    msg!("Withdrew {} KNOW, balance: {}", amount, stake_account.deposited);
+
+    Ok(())
+}
+
+// ============================================================
+// ORACLE BOND
+// ============================================================
+
+#[derive(Accounts)]
+pub struct BondOracleStake<'info> {
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Move available KNOW into the Oracle bond
+pub fn bond_oracle_stake(ctx: Context<BondOracleStake>, amount: u64) -> Result<()> {
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    require!(amount > 0, KnomeeError::InsufficientStake);
+    require!(
+        amount <= stake_account.available(),
+        KnomeeError::InsufficientAvailableStake
+    );
+
+    stake_account.bonded = stake_account
+        .bonded
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!("Bonded {} KNOW, total bond: {}", amount, stake_account.bonded);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnbondOracleStake<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        seeds = [IDENTITY_SEED, owner.key().as_ref()],
+        bump = identity.bump
+    )]
+    pub identity: Account<'info, Identity>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Move bonded KNOW into unbonding (Oracles must keep the minimum bond)
+pub fn unbond_oracle_stake(ctx: Context<UnbondOracleStake>, amount: u64) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let current_time = governance.current_time();
+
+    require!(amount > 0, KnomeeError::InsufficientStake);
+    // A losing Sybil vote slashes the bond at settlement, so it stays put until every vouch settles
+    require!(
+        stake_account.active_vouches == 0,
+        KnomeeError::StakeHasActiveVouches
+    );
+    let remaining = stake_account
+        .bonded
+        .checked_sub(amount)
+        .ok_or(KnomeeError::InsufficientAvailableStake)?;
+    require!(
+        !ctx.accounts.identity.is_oracle() || remaining >= governance.params.min_oracle_bond,
+        KnomeeError::OracleBondTooLow
+    );
+
+    stake_account.bonded = remaining;
+    stake_account.unbonding = stake_account
+        .unbonding
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    stake_account.unbonding_available_at = current_time
+        .checked_add(governance.params.stake_unbonding_period)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!("Unbonding {} KNOW from Oracle bond, remaining bond: {}", amount, remaining);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EnforceOracleBond<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, identity.owner.as_ref()],
+        bump = identity.bump,
+        constraint = identity.is_oracle() @ KnomeeError::NotAnOracle
+    )]
+    pub identity: Account<'info, Identity>,
+
+    /// Oracle's stake account (required, so the bond cannot be hidden by omitting it)
+    #[account(
+        seeds = [STAKE_ACCOUNT_SEED, identity.owner.as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    pub tier_token: TierTokenAccounts<'info>,
+}
+
+/// Permissionless: demote an Oracle whose bond is below the governance minimum
+pub fn enforce_oracle_bond(ctx: Context<EnforceOracleBond>) -> Result<()> {
+    let min_bond = ctx.accounts.governance.params.min_oracle_bond;
+    let bonded = ctx.accounts.stake_account.bonded;
+
+    require!(bonded < min_bond, KnomeeError::OracleBondSufficient);
+
+    let identity = &mut ctx.accounts.identity;
+    identity.tier = IdentityTier::PrimaryID;
+    ctx.accounts
+        .tier_token
+        .sync(identity, ctx.bumps.tier_token.token_authority)?;
+
+    msg!("Oracle {} demoted: bond {} below minimum {}", identity.owner, bonded, min_bond);
+
+    Ok(())
+}
+
+// ============================================================
+// HELPERS
+// ============================================================
+
+/// Reject closing an owner's Identity while their stake account (if any) still has
+/// vouches to settle, since settlement updates that Identity. With `require_unbonded`,
+/// also reject an Oracle bond, which can only be released through the Identity.
+pub(crate) fn require_stake_settled(stake_account: &AccountInfo, require_unbonded: bool) -> Result<()> {
+    if stake_account.data_is_empty() {
+        return Ok(());
+    }
+    let stake = StakeAccount::try_deserialize(&mut &stake_account.try_borrow_data()?[..])?;
+    require!(stake.active_vouches == 0, KnomeeError::StakeHasActiveVouches);
+    require!(
+        !require_unbonded || stake.bonded == 0,
+        KnomeeError::StakeStillBonded
+    );
+    Ok(())
+}
+
+/// Move a lost key's Oracle bond (if any) to the recovering key's stake account, so the
+/// bond follows the tier. Returns the recovering key's bond afterwards.
+pub(crate) fn move_oracle_bond(
+    stake_account: &AccountInfo,
+    new_stake_account: Option<&mut StakeAccount>,
+) -> Result<u64> {
+    let bonded = |stake: &Option<&mut StakeAccount>| stake.as_ref().map_or(0, |stake| stake.bonded);
+    if stake_account.data_is_empty() {
+        return Ok(bonded(&new_stake_account));
+    }
+    let mut stake = StakeAccount::try_deserialize(&mut &stake_account.try_borrow_data()?[..])?;
+    if stake.bonded == 0 {
+        return Ok(bonded(&new_stake_account));
+    }
+
+    let new_stake = new_stake_account.ok_or(KnomeeError::StakeAccountRequired)?;
+    let amount = stake
+        .transfer_bond_to(new_stake)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    stake.try_serialize(&mut &mut stake_account.try_borrow_mut_data()?[..])?;
+
+    msg!("Moved Oracle bond of {} KNOW to {}", amount, new_stake.owner);
+
+    Ok(new_stake.bonded)
+}
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::KnomeeError, events::TreasuryWithdrawal, state::*};
+
+// ============================================================
+// INITIALIZE TREASURY (GOVERNANCE ONLY)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::LEN,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub know_mint: Account<'info, Mint>,
+
+    /// Treasury vault, owned by the governance PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [TREASURY_VAULT_SEED],
+        bump,
+        token::mint = know_mint,
+        token::authority = governance
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    /// Stake escrow, owned by itself so the program can pay out and slash stakes
+    #[account(
+        init,
+        payer = authority,
+        seeds = [STAKE_ESCROW_SEED],
+        bump,
+        token::mint = know_mint,
+        token::authority = stake_escrow
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+
+    treasury.vault = ctx.accounts.treasury_vault.key();
+    treasury.stake_escrow = ctx.accounts.stake_escrow.key();
+    treasury.total_withdrawn = 0;
+    treasury.withdrawal_count = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    msg!("Treasury vault: {}", treasury.vault);
+    msg!("Stake escrow: {}", treasury.stake_escrow);
+
+    Ok(())
+}
+
+// ============================================================
+// WITHDRAW TREASURY (GOVERNANCE ONLY)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        address = treasury.vault
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let governance = &ctx.accounts.governance;
+    let treasury = &mut ctx.accounts.treasury;
+
+    require!(amount > 0, KnomeeError::InvalidWithdrawalAmount);
+    require!(
+        ctx.accounts.treasury_vault.amount >= amount,
+        KnomeeError::InsufficientTreasury
+    );
+
+    let signer_seeds: &[&[u8]] = &[GOVERNANCE_SEED, &[governance.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury_vault.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: governance.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )?;
+
+    treasury.total_withdrawn = treasury
+        .total_withdrawn
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    treasury.withdrawal_count = treasury
+        .withdrawal_count
+        .checked_add(1)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    emit!(TreasuryWithdrawal {
+        authority: ctx.accounts.authority.key(),
+        recipient: ctx.accounts.recipient_token_account.key(),
+        amount,
+        remaining_balance: ctx.accounts.treasury_vault.amount - amount,
+        withdrawal_index: treasury.withdrawal_count,
+        timestamp: governance.current_time(),
+    });
+
+    msg!("Treasury withdrawal of {} KNOW", amount);
+
+    Ok(())
+}
+
+// ============================================================
+// HELPERS
+// ============================================================
+
+/// Charge the governance claim-creation fee (if any) into the treasury vault
+pub(crate) fn collect_claim_fee<'info>(
+    fee: u64,
+    from: &Account<'info, TokenAccount>,
+    authority: &Signer<'info>,
+    treasury_vault: &Option<Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+
+    let treasury_vault = treasury_vault
+        .as_ref()
+        .ok_or(KnomeeError::TreasuryVaultRequired)?;
+
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: treasury_vault.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+        ),
+        fee,
+    )?;
+
+    msg!("Claim fee of {} KNOW paid to treasury", fee);
+
+    Ok(())
+}
@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::{constants::*, errors::KnomeeError, state::*};
+
+// ============================================================
+// INITIALIZE UBI POOL (GOVERNANCE ONLY)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct InitializeUbiPool<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = UbiPool::LEN,
+        seeds = [UBI_POOL_SEED],
+        bump
+    )]
+    pub ubi_pool: Account<'info, UbiPool>,
+
+    pub know_mint: Account<'info, Mint>,
+
+    /// Funded by plain KNOW transfers from anyone
+    #[account(
+        init,
+        payer = authority,
+        seeds = [UBI_VAULT_SEED],
+        bump,
+        token::mint = know_mint,
+        token::authority = ubi_pool
+    )]
+    pub ubi_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_ubi_pool(ctx: Context<InitializeUbiPool>) -> Result<()> {
+    let ubi_pool = &mut ctx.accounts.ubi_pool;
+
+    ubi_pool.know_mint = ctx.accounts.know_mint.key();
+    ubi_pool.vault = ctx.accounts.ubi_vault.key();
+    ubi_pool.current_epoch = 0;
+    ubi_pool.current_epoch_ends_at = 0;
+    ubi_pool.total_distributed = 0;
+    ubi_pool.bump = ctx.bumps.ubi_pool;
+
+    msg!("UBI pool initialized, vault: {}", ubi_pool.vault);
+
+    Ok(())
+}
+
+// ============================================================
+// START UBI EPOCH (GOVERNANCE ONLY)
+// ============================================================
+
+#[derive(Accounts)]
+pub struct StartUbiEpoch<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump,
+        has_one = authority @ KnomeeError::UnauthorizedGovernance
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [UBI_POOL_SEED],
+        bump = ubi_pool.bump
+    )]
+    pub ubi_pool: Account<'info, UbiPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Epoch::LEN,
+        seeds = [EPOCH_SEED, &(ubi_pool.current_epoch + 1).to_le_bytes()],
+        bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn start_ubi_epoch(
+    ctx: Context<StartUbiEpoch>,
+    per_capita_amount: u64,
+    duration: i64,
+) -> Result<()> {
+    let ubi_pool = &mut ctx.accounts.ubi_pool;
+    let epoch = &mut ctx.accounts.epoch;
+    let current_time = ctx.accounts.governance.current_time();
+
+    require!(per_capita_amount > 0, KnomeeError::InvalidUbiAmount);
+    require!(duration >= MIN_UBI_EPOCH_DURATION, KnomeeError::InvalidEpochDuration);
+    require!(
+        current_time >= ubi_pool.current_epoch_ends_at,
+        KnomeeError::EpochStillActive
+    );
+
+    let epoch_number = ubi_pool
+        .current_epoch
+        .checked_add(1)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    let ends_at = current_time
+        .checked_add(duration)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    epoch.epoch_number = epoch_number;
+    epoch.per_capita_amount = per_capita_amount;
+    epoch.starts_at = current_time;
+    epoch.ends_at = ends_at;
+    epoch.claimant_count = 0;
+    epoch.total_claimed = 0;
+    epoch.bump = ctx.bumps.epoch;
+
+    ubi_pool.current_epoch = epoch_number;
+    ubi_pool.current_epoch_ends_at = ends_at;
+
+    msg!("UBI epoch {} started", epoch_number);
+    msg!("Per-capita amount: {} KNOW, ends at {}", per_capita_amount, ends_at);
+
+    Ok(())
+}
+
+// ============================================================
+// CLAIM UBI
+// ============================================================
+
+#[derive(Accounts)]
+#[instruction(epoch_number: u64)]
+pub struct ClaimUbi<'info> {
+    #[account(
+        seeds = [GOVERNANCE_SEED],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [UBI_POOL_SEED],
+        bump = ubi_pool.bump
+    )]
+    pub ubi_pool: Account<'info, UbiPool>,
+
+    #[account(
+        mut,
+        address = ubi_pool.vault
+    )]
+    pub ubi_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_SEED, &epoch_number.to_le_bytes()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, Epoch>,
+
+    #[account(
+        mut,
+        seeds = [IDENTITY_SEED, owner.key().as_ref()],
+        bump = identity.bump,
+        constraint = identity.is_primary() @ KnomeeError::NotAPrimaryId,
+        constraint = !identity.under_challenge @ KnomeeError::AddressUnderChallenge
+    )]
+    pub identity: Account<'info, Identity>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == ubi_pool.know_mint @ KnomeeError::InvalidKnowMint
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Claim this epoch's per-capita UBI (once per identity per epoch)
+pub fn claim_ubi(ctx: Context<ClaimUbi>, epoch_number: u64) -> Result<()> {
+    let ubi_pool = &mut ctx.accounts.ubi_pool;
+    let epoch = &mut ctx.accounts.epoch;
+    let identity = &mut ctx.accounts.identity;
+    let current_time = ctx.accounts.governance.current_time();
+
+    require!(epoch.is_active(current_time), KnomeeError::EpochNotActive);
+    require!(
+        identity.last_ubi_epoch < epoch_number,
+        KnomeeError::UbiAlreadyClaimed
+    );
+
+    let amount = epoch.per_capita_amount;
+    require!(
+        ctx.accounts.ubi_vault.amount >= amount,
+        KnomeeError::InsufficientUbiVault
+    );
+
+    let signer_seeds: &[&[u8]] = &[UBI_POOL_SEED, &[ubi_pool.bump]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.ubi_vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ubi_pool.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )?;
+
+    identity.last_ubi_epoch = epoch_number;
+    epoch.claimant_count = epoch
+        .claimant_count
+        .checked_add(1)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    epoch.total_claimed = epoch
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+    ubi_pool.total_distributed = ubi_pool
+        .total_distributed
+        .checked_add(amount)
+        .ok_or(KnomeeError::ArithmeticOverflow)?;
+
+    msg!("UBI claimed for epoch {}: {} KNOW", epoch_number, amount);
+
+    Ok(())
+}
@@ -2,10 +2,12 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::*;
 
 declare_id!("KNoMeeID11111111111111111111111111111111111");
 
@@ -43,6 +45,20 @@ pub mod knomee_identity {
         instructions::governance::renounce_god_mode(ctx)
     }
 
+    /// Register a recognized platform identifier (governance only)
+    pub fn register_platform(ctx: Context<RegisterPlatform>, platform: String) -> Result<()> {
+        instructions::governance::register_platform(ctx, platform)
+    }
+
+    /// Enable or disable a registered platform (governance only)
+    pub fn set_platform_enabled(
+        ctx: Context<SetPlatformEnabled>,
+        platform: String,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::governance::set_platform_enabled(ctx, platform, enabled)
+    }
+
     // ============================================================
     // IDENTITY MANAGEMENT
     // ============================================================
@@ -61,8 +77,48 @@ pub mod knomee_identity {
     pub fn link_identity(
         ctx: Context<LinkIdentity>,
         platform: String,
+        index: u16,
+    ) -> Result<()> {
+        instructions::identity::link_identity(ctx, platform, index)
+    }
+
+    /// Move an identity to a new key, revoking the old one
+    pub fn migrate_identity(ctx: Context<MigrateIdentity>) -> Result<()> {
+        instructions::identity::migrate_identity(ctx)
+    }
+
+    /// Move a linked identity record to the migrated primary's new key
+    pub fn migrate_linked_identity(
+        ctx: Context<MigrateLinkedIdentity>,
+        platform: String,
+        index: u16,
+    ) -> Result<()> {
+        instructions::identity::migrate_linked_identity(ctx, platform, index)
+    }
+
+    /// Move a recovered identity to its new key after an approved Recovery claim
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+        instructions::identity::execute_recovery(ctx)
+    }
+
+    /// Unlink a secondary account from its primary identity
+    pub fn unlink_identity(
+        ctx: Context<UnlinkIdentity>,
+        platform: String,
+        index: u16,
     ) -> Result<()> {
-        instructions::identity::link_identity(ctx, platform)
+        instructions::identity::unlink_identity(ctx, platform, index)
+    }
+
+    /// Move a linked account to a different platform label
+    pub fn relink_identity(
+        ctx: Context<RelinkIdentity>,
+        old_platform: String,
+        old_index: u16,
+        new_platform: String,
+        new_index: u16,
+    ) -> Result<()> {
+        instructions::identity::relink_identity(ctx, old_platform, old_index, new_platform, new_index)
     }
 
     // ============================================================
@@ -106,27 +162,280 @@ pub mod knomee_identity {
         instructions::consensus::challenge_duplicate(ctx, addr1, addr2, evidence, stake_amount)
     }
 
+    /// Claim ownership of a PrimaryID whose key was lost (social recovery)
+    pub fn request_recovery(
+        ctx: Context<RequestRecovery>,
+        lost_address: Pubkey,
+        justification: String,
+        stake_amount: u64,
+    ) -> Result<()> {
+        instructions::consensus::request_recovery(ctx, lost_address, justification, stake_amount)
+    }
+
     /// Vote FOR a claim
-    pub fn vouch_for(ctx: Context<VouchFor>, claim_id: u64, stake_amount: u64) -> Result<()> {
+    pub fn vouch_for<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VouchFor<'info>>,
+        claim_id: u64,
+        stake_amount: u64,
+    ) -> Result<()> {
         instructions::consensus::vouch_for(ctx, claim_id, stake_amount)
     }
 
     /// Vote AGAINST a claim
-    pub fn vouch_against(
-        ctx: Context<VouchAgainst>,
+    pub fn vouch_against<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VouchAgainst<'info>>,
         claim_id: u64,
         stake_amount: u64,
     ) -> Result<()> {
         instructions::consensus::vouch_against(ctx, claim_id, stake_amount)
     }
 
+    /// Attach a rationale (text and/or content hash) to your vouch while the claim is open
+    pub fn set_vouch_rationale(
+        ctx: Context<SetVouchRationale>,
+        claim_id: u64,
+        rationale: String,
+        rationale_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::consensus::set_vouch_rationale(ctx, claim_id, rationale, rationale_hash)
+    }
+
     /// Resolve a claim after voting period ends
     pub fn resolve_consensus(ctx: Context<ResolveConsensus>, claim_id: u64) -> Result<()> {
         instructions::consensus::resolve_consensus(ctx, claim_id)
     }
 
-    /// Claim rewards from a resolved claim
+    /// Appeal a resolved claim within the appeal window
+    pub fn appeal_claim(
+        ctx: Context<AppealClaim>,
+        original_claim_id: u64,
+        justification: String,
+        stake_amount: u64,
+    ) -> Result<()> {
+        instructions::consensus::appeal_claim(ctx, original_claim_id, justification, stake_amount)
+    }
+
+    /// Settle a vouch on a resolved claim (permissionless)
     pub fn claim_rewards(ctx: Context<ClaimRewards>, claim_id: u64) -> Result<()> {
         instructions::consensus::claim_rewards(ctx, claim_id)
     }
+
+    /// Refund or slash the claim creator's stake once the outcome is final (permissionless)
+    pub fn settle_creator_stake(ctx: Context<SettleCreatorStake>, claim_id: u64) -> Result<()> {
+        instructions::consensus::settle_creator_stake(ctx, claim_id)
+    }
+
+    /// Close a settled vouch account (rent back to the voucher)
+    pub fn close_vouch(ctx: Context<CloseVouch>, claim_id: u64) -> Result<()> {
+        instructions::consensus::close_vouch(ctx, claim_id)
+    }
+
+    /// Close a resolved claim once all vouches are closed (rent back to the creator)
+    pub fn close_claim(ctx: Context<CloseClaim>, claim_id: u64) -> Result<()> {
+        instructions::consensus::close_claim(ctx, claim_id)
+    }
+
+    // ============================================================
+    // EVIDENCE
+    // ============================================================
+
+    /// Attach a structured evidence entry (content hash + URI) to an active claim
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        claim_id: u64,
+        content_hash: [u8; 32],
+        uri: String,
+        media_type: String,
+    ) -> Result<()> {
+        instructions::evidence::submit_evidence(ctx, claim_id, content_hash, uri, media_type)
+    }
+
+    /// Close an evidence entry after its claim is final (rent back to the submitter)
+    pub fn close_evidence(ctx: Context<CloseEvidence>, claim_id: u64, index: u32) -> Result<()> {
+        instructions::evidence::close_evidence(ctx, claim_id, index)
+    }
+
+    // ============================================================
+    // KNOW REWARDS
+    // ============================================================
+
+    /// Create the KNOW rewards pool and vault (governance only)
+    pub fn initialize_rewards_pool(ctx: Context<InitializeRewardsPool>) -> Result<()> {
+        instructions::rewards::initialize_rewards_pool(ctx)
+    }
+
+    /// Mint KNOW into the rewards vault (governance only)
+    pub fn fund_rewards_pool(ctx: Context<FundRewardsPool>, amount: u64) -> Result<()> {
+        instructions::rewards::fund_rewards_pool(ctx, amount)
+    }
+
+    /// Claim the one-time Primary ID verification reward
+    pub fn claim_primary_reward(ctx: Context<ClaimPrimaryReward>) -> Result<()> {
+        instructions::rewards::claim_primary_reward(ctx)
+    }
+
+    /// Claim the KNOW voting (and Oracle) reward for a winning vouch
+    pub fn claim_voting_reward(ctx: Context<ClaimVotingReward>, claim_id: u64) -> Result<()> {
+        instructions::rewards::claim_voting_reward(ctx, claim_id)
+    }
+
+    // ============================================================
+    // JURY
+    // ============================================================
+
+    /// Draw the random jury for a claim (permissionless)
+    pub fn draw_jury(ctx: Context<DrawJury>, claim_id: u64) -> Result<()> {
+        instructions::jury::draw_jury(ctx, claim_id)
+    }
+
+    /// Move a claim's jury draw to a future slot once its source slot hash is unavailable (permissionless)
+    pub fn reanchor_jury_draw(ctx: Context<ReanchorJuryDraw>, claim_id: u64) -> Result<()> {
+        instructions::jury::reanchor_jury_draw(ctx, claim_id)
+    }
+
+    // ============================================================
+    // DELEGATION
+    // ============================================================
+
+    /// Delegate a Primary's vote weight to another voting identity
+    pub fn delegate_vote(ctx: Context<DelegateVote>) -> Result<()> {
+        instructions::delegation::delegate_vote(ctx)
+    }
+
+    /// Revoke a vote delegation
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::delegation::revoke_delegation(ctx)
+    }
+
+    // ============================================================
+    // STAKING
+    // ============================================================
+
+    /// Create a stake account for depositing KNOW
+    pub fn initialize_stake_account(ctx: Context<InitializeStakeAccount>) -> Result<()> {
+        instructions::stake::initialize_stake_account(ctx)
+    }
+
+    /// Deposit KNOW into the stake account
+    pub fn deposit_stake(ctx: Context<DepositStake>, amount: u64) -> Result<()> {
+        instructions::stake::deposit_stake(ctx, amount)
+    }
+
+    /// Start unbonding unlocked KNOW
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
+        instructions::stake::request_unstake(ctx, amount)
+    }
+
+    /// Withdraw KNOW after the unbonding period
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        instructions::stake::withdraw_stake(ctx)
+    }
+
+    /// Bond available KNOW toward the Oracle minimum
+    pub fn bond_oracle_stake(ctx: Context<BondOracleStake>, amount: u64) -> Result<()> {
+        instructions::stake::bond_oracle_stake(ctx, amount)
+    }
+
+    /// Start unbonding part of the Oracle bond
+    pub fn unbond_oracle_stake(ctx: Context<UnbondOracleStake>, amount: u64) -> Result<()> {
+        instructions::stake::unbond_oracle_stake(ctx, amount)
+    }
+
+    /// Demote an Oracle whose bond is below the minimum (permissionless)
+    pub fn enforce_oracle_bond(ctx: Context<EnforceOracleBond>) -> Result<()> {
+        instructions::stake::enforce_oracle_bond(ctx)
+    }
+
+    // ============================================================
+    // TREASURY
+    // ============================================================
+
+    /// Create the treasury vault and stake escrow (governance only)
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::treasury::initialize_treasury(ctx)
+    }
+
+    /// Withdraw KNOW from the treasury (governance only)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::treasury::withdraw_treasury(ctx, amount)
+    }
+
+    // ============================================================
+    // UBI
+    // ============================================================
+
+    /// Create the UBI pool and vault (governance only)
+    pub fn initialize_ubi_pool(ctx: Context<InitializeUbiPool>) -> Result<()> {
+        instructions::ubi::initialize_ubi_pool(ctx)
+    }
+
+    /// Open the next UBI epoch with a per-capita amount (governance only)
+    pub fn start_ubi_epoch(
+        ctx: Context<StartUbiEpoch>,
+        per_capita_amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::ubi::start_ubi_epoch(ctx, per_capita_amount, duration)
+    }
+
+    /// Claim UBI for an epoch (Primary/Oracle, once per epoch)
+    pub fn claim_ubi(ctx: Context<ClaimUbi>, epoch_number: u64) -> Result<()> {
+        instructions::ubi::claim_ubi(ctx, epoch_number)
+    }
+
+    // ============================================================
+    // IDENTITY TOKENS
+    // ============================================================
+
+    /// Create the soul-bound Token-2022 mint for a tier (governance only)
+    pub fn initialize_tier_mint(ctx: Context<InitializeTierMint>, tier: IdentityTier) -> Result<()> {
+        instructions::identity_token::initialize_tier_mint(ctx, tier)
+    }
+
+    /// Catch up an identity token whose tier mint did not exist when the tier changed
+    pub fn sync_identity_token(ctx: Context<SyncIdentityToken>) -> Result<()> {
+        instructions::identity_token::sync_identity_token(ctx)
+    }
+
+    // ============================================================
+    // GUARDIAN RECOVERY
+    // ============================================================
+
+    /// Register guardians and an M-of-N threshold for recovering this identity
+    pub fn configure_recovery(
+        ctx: Context<ConfigureRecovery>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        veto_delay: i64,
+    ) -> Result<()> {
+        instructions::recovery::configure_recovery(ctx, guardians, threshold, veto_delay)
+    }
+
+    /// Replace guardians and threshold (clears any pending proposal)
+    pub fn update_recovery_config(
+        ctx: Context<UpdateRecoveryConfig>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        veto_delay: i64,
+    ) -> Result<()> {
+        instructions::recovery::update_recovery_config(ctx, guardians, threshold, veto_delay)
+    }
+
+    /// Guardian approval to rotate the identity to a new owner
+    pub fn approve_guardian_recovery(
+        ctx: Context<ApproveGuardianRecovery>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::recovery::approve_guardian_recovery(ctx, new_owner)
+    }
+
+    /// Owner veto of a pending guardian recovery
+    pub fn veto_guardian_recovery(ctx: Context<VetoGuardianRecovery>) -> Result<()> {
+        instructions::recovery::veto_guardian_recovery(ctx)
+    }
+
+    /// Rotate the identity to the approved new owner after the veto window
+    pub fn execute_guardian_recovery(ctx: Context<ExecuteGuardianRecovery>) -> Result<()> {
+        instructions::recovery::execute_guardian_recovery(ctx)
+    }
 }
@@ -1,10 +1,14 @@
 use anchor_lang::prelude::*;
+use crate::state::StakeWeighting;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ClaimType {
+    #[default]
     LinkToPrimary,   // 51% threshold
     NewPrimary,      // 67% threshold
     DuplicateFlag,   // 80% threshold
+    Appeal,          // Governance threshold, overturns a resolved claim
+    Recovery,        // Governance threshold, moves a lost PrimaryID to a new key
 }
 
 impl ClaimType {
@@ -13,6 +17,8 @@ impl ClaimType {
             ClaimType::LinkToPrimary => 0,
             ClaimType::NewPrimary => 1,
             ClaimType::DuplicateFlag => 2,
+            ClaimType::Appeal => 3,
+            ClaimType::Recovery => 4,
         }
     }
 
@@ -21,6 +27,8 @@ impl ClaimType {
             0 => Some(ClaimType::LinkToPrimary),
             1 => Some(ClaimType::NewPrimary),
             2 => Some(ClaimType::DuplicateFlag),
+            3 => Some(ClaimType::Appeal),
+            4 => Some(ClaimType::Recovery),
             _ => None,
         }
     }
@@ -30,6 +38,8 @@ impl ClaimType {
             ClaimType::LinkToPrimary => params.link_threshold,
             ClaimType::NewPrimary => params.primary_threshold,
             ClaimType::DuplicateFlag => params.duplicate_threshold,
+            ClaimType::Appeal => params.appeal_threshold,
+            ClaimType::Recovery => params.recovery_threshold,
         }
     }
 
@@ -41,6 +51,8 @@ impl ClaimType {
             ClaimType::LinkToPrimary => params.link_slash_bps,
             ClaimType::NewPrimary => params.primary_slash_bps,
             ClaimType::DuplicateFlag => params.duplicate_slash_bps,
+            ClaimType::Appeal => params.appeal_slash_bps,
+            ClaimType::Recovery => params.primary_slash_bps,
         }
     }
 
@@ -49,6 +61,8 @@ impl ClaimType {
             ClaimType::LinkToPrimary => 1,
             ClaimType::NewPrimary => params.primary_stake_multiplier as u64,
             ClaimType::DuplicateFlag => params.duplicate_stake_multiplier as u64,
+            ClaimType::Appeal => params.appeal_stake_multiplier as u64,
+            ClaimType::Recovery => params.recovery_stake_multiplier as u64,
         }
     }
 
@@ -57,12 +71,15 @@ impl ClaimType {
             ClaimType::LinkToPrimary => params.failed_claim_cooldown,
             ClaimType::NewPrimary => params.failed_claim_cooldown,
             ClaimType::DuplicateFlag => params.duplicate_flag_cooldown,
+            ClaimType::Appeal => params.failed_claim_cooldown,
+            ClaimType::Recovery => params.failed_claim_cooldown,
         }
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
 pub enum ClaimStatus {
+    #[default]
     Active,      // Currently accepting votes
     Approved,    // Consensus reached, claim approved
     Rejected,    // Consensus reached, claim rejected
@@ -94,7 +111,29 @@ impl ClaimStatus {
     }
 }
 
+/// Slash rates and split snapshotted from governance when a claim resolves
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SlashTerms {
+    /// Slash rate on losing vouches (basis points)
+    pub voter_slash_bps: u16,
+    /// Slash rate on losing Oracles' bonds (DuplicateFlag claims only)
+    pub oracle_bond_slash_bps: u16,
+    /// Split of slashed stake (basis points, sums to 10000)
+    pub winners_bps: u16,
+    pub treasury_bps: u16,
+    pub burn_bps: u16,
+}
+
+impl SlashTerms {
+    pub const LEN: usize = 2 + // voter_slash_bps
+        2 + // oracle_bond_slash_bps
+        2 + // winners_bps
+        2 + // treasury_bps
+        2; // burn_bps
+}
+
 #[account]
+#[derive(Default)]
 pub struct IdentityClaim {
     /// Unique claim ID
     pub claim_id: u64,
@@ -108,7 +147,10 @@ pub struct IdentityClaim {
     /// Address making the claim / being challenged
     pub subject: Pubkey,
 
-    /// Related address (primary for Link, duplicate for Flag, unused for NewPrimary)
+    /// Account that created the claim and paid its rent
+    pub creator: Pubkey,
+
+    /// Related address (primary for Link, duplicate for Flag, lost key for Recovery, unused for NewPrimary)
     pub related_address: Pubkey,
 
     /// Platform name (for LinkToPrimary claims)
@@ -135,12 +177,69 @@ pub struct IdentityClaim {
     /// Total KNOW slashed from incorrect votes
     pub total_slashed: u64,
 
+    /// KNOW staked by vouches FOR
+    pub stake_for: u64,
+
+    /// KNOW staked by vouches AGAINST
+    pub stake_against: u64,
+
     /// Number of vouches (for iteration)
     pub vouch_count: u32,
 
+    /// Number of vouches that have called `claim_rewards`
+    pub settled_vouch_count: u32,
+
+    /// Number of vouch accounts closed
+    pub closed_vouch_count: u32,
+
+    /// Number of evidence entries submitted
+    pub evidence_count: u32,
+
+    /// Number of evidence accounts closed
+    pub closed_evidence_count: u32,
+
     /// Whether rewards have been distributed
     pub rewards_distributed: bool,
 
+    /// Stake weighting mode snapshotted from governance at creation
+    pub stake_weighting: StakeWeighting,
+
+    /// Stake cap snapshotted from governance (used by `StakeWeighting::Capped`)
+    pub stake_weight_cap: u64,
+
+    /// When the claim was resolved (0 while active)
+    pub resolved_at: i64,
+
+    /// Slash terms snapshotted at resolution (re-taken if an appeal flips the outcome)
+    pub slash_terms: SlashTerms,
+
+    /// For Appeal claims, the ID of the claim being appealed (0 otherwise)
+    pub appeal_of: u64,
+
+    /// ID of the appeal opened against this claim (0 if never appealed)
+    pub appeal_claim_id: u64,
+
+    /// Whether the appeal opened against this claim has been resolved
+    pub appeal_resolved: bool,
+
+    /// Consensus threshold snapshotted at creation (0 = the claim type's governance threshold)
+    pub threshold_bps: u16,
+
+    /// Whether only Oracles may vote on this claim
+    pub oracle_only: bool,
+
+    /// Slot the claim was created in (anchors the jury draw)
+    pub created_slot: u64,
+
+    /// Jury selection rate snapshotted from governance (0 = open voting)
+    pub jury_selection_bps: u16,
+
+    /// KNOW staked by the creator when opening the claim (included in `total_stake`)
+    pub creator_stake: u64,
+
+    /// Whether the creator's stake has been refunded or slashed
+    pub creator_stake_settled: bool,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -153,6 +252,7 @@ impl IdentityClaim {
         1 +    // claim_type
         1 +    // status
         32 +   // subject
+        32 +   // creator
         32 +   // related_address
         4 + platform_len + // platform (String with length prefix)
         4 + justification_len + // justification
@@ -162,11 +262,87 @@ impl IdentityClaim {
         16 +   // total_votes_against (u128)
         8 +    // total_stake
         8 +    // total_slashed
+        8 +    // stake_for
+        8 +    // stake_against
         4 +    // vouch_count
+        4 +    // settled_vouch_count
+        4 +    // closed_vouch_count
+        4 +    // evidence_count
+        4 +    // closed_evidence_count
         1 +    // rewards_distributed
+        1 +    // stake_weighting
+        8 +    // stake_weight_cap
+        8 +    // resolved_at
+        SlashTerms::LEN + // slash_terms
+        8 +    // appeal_of
+        8 +    // appeal_claim_id
+        1 +    // appeal_resolved
+        2 +    // threshold_bps
+        1 +    // oracle_only
+        8 +    // created_slot
+        2 +    // jury_selection_bps
+        8 +    // creator_stake
+        1 +    // creator_stake_settled
         1      // bump
     }
 
+    /// Slash rate applied to vouches on the losing side
+    pub fn voter_slash_rate(&self, params: &crate::state::GovernanceParams) -> u16 {
+        let is_sybil = matches!(self.claim_type, ClaimType::DuplicateFlag)
+            && matches!(self.status, ClaimStatus::Approved);
+        self.claim_type.slash_rate(params, is_sybil)
+    }
+
+    /// Record the slash terms for the current outcome, so later governance updates
+    /// do not change how this claim's vouches settle
+    pub fn snapshot_slash_terms(&mut self, params: &crate::state::GovernanceParams) {
+        self.slash_terms = SlashTerms {
+            voter_slash_bps: self.voter_slash_rate(params),
+            oracle_bond_slash_bps: params.oracle_bond_slash_bps,
+            winners_bps: params.slash_winners_bps,
+            treasury_bps: params.slash_treasury_bps,
+            burn_bps: params.slash_burn_bps,
+        };
+    }
+
+    /// Vouch stake on the winning side (0 unless Approved or Rejected)
+    pub fn winning_stake(&self) -> u64 {
+        match self.status {
+            ClaimStatus::Approved => self.stake_for,
+            ClaimStatus::Rejected => self.stake_against,
+            _ => 0,
+        }
+    }
+
+    /// Vouch stake on the losing side (0 unless Approved or Rejected)
+    pub fn losing_stake(&self) -> u64 {
+        match self.status {
+            ClaimStatus::Approved => self.stake_against,
+            ClaimStatus::Rejected => self.stake_for,
+            _ => 0,
+        }
+    }
+
+    /// Creator's stake slashed through the split (all of it if rejected, 0 otherwise)
+    pub fn slashed_creator_stake(&self) -> u64 {
+        match self.status {
+            ClaimStatus::Rejected => self.creator_stake,
+            _ => 0,
+        }
+    }
+
+    /// Slashed stake shared pro rata among winning vouches: the winners' share of
+    /// slashed losing vouches, plus of the creator's stake if rejected
+    pub fn winners_pool(&self) -> u128 {
+        let bps = crate::constants::BASIS_POINTS as u128;
+        let slashed_votes = (self.losing_stake() as u128)
+            * (self.slash_terms.voter_slash_bps as u128)
+            / bps;
+        (slashed_votes + self.slashed_creator_stake() as u128)
+            * (self.slash_terms.winners_bps as u128)
+            / bps
+    }
+
     /// Calculate current consensus percentage (in basis points)
     pub fn consensus_for_bps(&self) -> u16 {
         let total_votes = self.total_votes_for + self.total_votes_against;
@@ -177,13 +353,70 @@ impl IdentityClaim {
         percentage.min(10000) as u16
     }
 
+    /// Largest stake, up to `stake`, at which a new vouch of `weight` stays within
+    /// `max_weight_share_bps` of the claim's total weight after it is counted. A
+    /// minimum-stake vouch is never reduced, so the cap only limits stake beyond the
+    /// minimum, whatever the voucher's tier or the order of votes.
+    pub fn capped_stake(&self, weight: u64, stake: u64, params: &crate::state::GovernanceParams) -> u64 {
+        let max_share_bps = params.max_weight_share_bps;
+        if max_share_bps >= crate::constants::BASIS_POINTS || stake <= params.min_stake_lamports {
+            return stake;
+        }
+        let vote = |stake: u64| {
+            (weight as u128).saturating_mul(
+                self.stake_weighting.effective_stake(stake, self.stake_weight_cap),
+            )
+        };
+        // w / (total + w) <= share  =>  w <= total * share / (10000 - share)
+        let max_vote = self
+            .total_votes_for
+            .saturating_add(self.total_votes_against)
+            .saturating_mul(max_share_bps as u128)
+            / (crate::constants::BASIS_POINTS - max_share_bps) as u128;
+        let max_vote = max_vote.max(vote(params.min_stake_lamports));
+        if vote(stake) <= max_vote {
+            return stake;
+        }
+        // Effective stake is non-decreasing, so search for the last stake that fits
+        let (mut low, mut high) = (params.min_stake_lamports, stake);
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if vote(mid) <= max_vote {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Whether this claim can still be appealed at `current_time`
+    pub fn is_appealable(&self, appeal_window: i64, current_time: i64) -> bool {
+        matches!(self.status, ClaimStatus::Approved | ClaimStatus::Rejected)
+            && !matches!(self.claim_type, ClaimType::Appeal)
+            && self.appeal_claim_id == 0
+            && current_time <= self.resolved_at.saturating_add(appeal_window)
+    }
+
+    /// Whether the outcome can no longer change: resolved, past the appeal window
+    /// and with any appeal resolved
+    pub fn is_final(&self, appeal_window: i64, current_time: i64) -> bool {
+        self.status.is_resolved()
+            && !self.is_appealable(appeal_window, current_time)
+            && (self.appeal_claim_id == 0 || self.appeal_resolved)
+    }
+
     /// Check if consensus threshold is met
     pub fn consensus_reached(&self, params: &crate::state::GovernanceParams) -> Option<bool> {
         if !matches!(self.status, ClaimStatus::Active) {
             return None;
         }
 
-        let threshold = self.claim_type.required_threshold(params);
+        let threshold = if self.threshold_bps > 0 {
+            self.threshold_bps
+        } else {
+            self.claim_type.required_threshold(params)
+        };
         let consensus_bps = self.consensus_for_bps();
 
         if consensus_bps >= threshold {
@@ -195,3 +428,92 @@ impl IdentityClaim {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GovernanceParams;
+
+    fn params(max_share_bps: u16) -> GovernanceParams {
+        GovernanceParams {
+            max_weight_share_bps: max_share_bps,
+            min_stake_lamports: 100,
+            primary_vote_weight: 1,
+            ..GovernanceParams::default()
+        }
+    }
+
+    fn claim(total_for: u128, total_against: u128) -> IdentityClaim {
+        IdentityClaim {
+            total_votes_for: total_for,
+            total_votes_against: total_against,
+            ..IdentityClaim::default()
+        }
+    }
+
+    #[test]
+    fn cap_disabled_at_full_share() {
+        let params = params(10000);
+        assert_eq!(claim(0, 0).capped_stake(u64::MAX, u64::MAX, &params), u64::MAX);
+        assert_eq!(claim(1, 0).capped_stake(1, 0, &params), 0);
+    }
+
+    #[test]
+    fn minimum_stake_vouch_is_never_reduced() {
+        // An opening Oracle vouch at the minimum keeps its full weight
+        let params = params(2000);
+        assert_eq!(claim(0, 0).capped_stake(100, 100, &params), 100);
+        assert_eq!(claim(0, 0).capped_stake(100, 1_000, &params), 100);
+        assert_eq!(claim(u128::MAX, 0).capped_stake(100, 1_000, &params), 1_000);
+    }
+
+    #[test]
+    fn cap_keeps_share_after_counting() {
+        // 20% share: weight * stake <= total / 4
+        let params = params(2000);
+        assert_eq!(claim(3_000, 1_000).capped_stake(1, 5_000, &params), 1_000);
+        assert_eq!(claim(3_000, 1_000).capped_stake(1, 999, &params), 999);
+        assert_eq!(claim(4_003, 0).capped_stake(1, u64::MAX, &params), 1_000);
+        assert_eq!(claim(4_003, 0).capped_stake(3, u64::MAX, &params), 333);
+    }
+
+    #[test]
+    fn cap_follows_stake_weighting() {
+        // Square root: 10 * sqrt(stake) <= 1_000 / 4  =>  stake < 676
+        let params = params(2000);
+        let mut claim = claim(1_000, 0);
+        claim.stake_weighting = StakeWeighting::SquareRoot;
+        assert_eq!(claim.capped_stake(10, 10_000, &params), 675);
+    }
+
+    #[test]
+    fn cap_saturates_on_large_totals() {
+        let params = params(1);
+        assert_eq!(claim(u128::MAX, u128::MAX).capped_stake(1, u64::MAX, &params), u64::MAX);
+    }
+
+    #[test]
+    fn slash_terms_follow_outcome_not_later_params() {
+        let mut params = GovernanceParams::default();
+        let mut flag = IdentityClaim {
+            claim_type: ClaimType::DuplicateFlag,
+            status: ClaimStatus::Approved,
+            ..IdentityClaim::default()
+        };
+
+        flag.snapshot_slash_terms(&params);
+        assert_eq!(flag.slash_terms.voter_slash_bps, params.sybil_slash_bps);
+        assert_eq!(flag.slash_terms.winners_bps, params.slash_winners_bps);
+
+        // Governance changes after resolution do not touch the snapshot
+        params.slash_burn_bps += params.slash_winners_bps;
+        params.slash_winners_bps = 0;
+        assert_eq!(flag.slash_terms.winners_bps, GovernanceParams::default().slash_winners_bps);
+
+        // An overturned outcome is re-snapshotted at the non-Sybil rate
+        flag.status = ClaimStatus::Rejected;
+        flag.snapshot_slash_terms(&params);
+        assert_eq!(flag.slash_terms.voter_slash_bps, params.duplicate_slash_bps);
+        assert_eq!(flag.slash_terms.winners_bps, 0);
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Vote delegation from a Primary to another voting identity (PDA derived from delegator)
+#[account]
+#[derive(Default)]
+pub struct Delegation {
+    /// Primary delegating its vote weight
+    pub delegator: Pubkey,
+
+    /// Identity whose vouches carry the delegated weight
+    pub delegate: Pubkey,
+
+    /// When the delegation was created (only claims created later can use it)
+    pub delegated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // delegator
+        32 + // delegate
+        8 +  // delegated_at
+        1;   // bump
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// Structured evidence entry attached to a claim (PDA derived from claim ID and index).
+/// Content lives off-chain; the hash lets voters verify what the URI serves.
+#[account]
+pub struct Evidence {
+    /// Claim this evidence is attached to
+    pub claim_id: u64,
+
+    /// Position in the claim's evidence list
+    pub index: u32,
+
+    /// Address that submitted the evidence (receives rent on close)
+    pub submitter: Pubkey,
+
+    /// Hash of the off-chain content (e.g. SHA-256)
+    pub content_hash: [u8; 32],
+
+    /// Where the content can be fetched (https://, ipfs://, ar://)
+    pub uri: String,
+
+    /// MIME type of the content (e.g. "image/png")
+    pub media_type: String,
+
+    /// When the evidence was submitted
+    pub submitted_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Evidence {
+    // Dynamic size based on string lengths
+    pub fn space(uri_len: usize, media_type_len: usize) -> usize {
+        8 +    // discriminator
+        8 +    // claim_id
+        4 +    // index
+        32 +   // submitter
+        32 +   // content_hash
+        4 + uri_len + // uri (String with length prefix)
+        4 + media_type_len + // media_type
+        8 +    // submitted_at
+        1      // bump
+    }
+}
@@ -62,6 +62,32 @@ pub struct GovernanceParams {
     pub duplicate_slash_bps: u16,
     pub sybil_slash_bps: u16,
 
+    // Split of slashed stake (basis points, sums to 10000)
+    pub slash_winners_bps: u16,
+    pub slash_treasury_bps: u16,
+    pub slash_burn_bps: u16,
+
+    // Claim creation fee paid to the treasury (0 = disabled)
+    pub claim_fee_lamports: u64,
+
+    // Crank bounty paid from the treasury for resolving a claim (0 = disabled), only
+    // once the claim has expired or drawn the minimum number of vouches
+    pub resolution_bounty_lamports: u64,
+    pub resolution_bounty_min_votes: u32,
+
+    // KNOW paid from the rewards pool per winning vouch (0 = disabled)
+    pub voting_reward: u64,
+
+    // Delay before unstaked KNOW can be withdrawn (in seconds)
+    pub stake_unbonding_period: i64,
+
+    // Oracle bond (minimum bonded KNOW and slash rate on losing Sybil votes)
+    pub min_oracle_bond: u64,
+    pub oracle_bond_slash_bps: u16,
+
+    // Random jury selection (share of voters drawn per claim, 0 = open voting)
+    pub jury_selection_bps: u16,
+
     // Voting weights
     pub primary_vote_weight: u64,
     pub oracle_vote_weight: u64,
@@ -74,6 +100,32 @@ pub struct GovernanceParams {
     // Oracle decay rates (basis points per day)
     pub oracle_decay_rate_bps: u16,
     pub admin_decay_rate_bps: u16,
+
+    // Stake weighting (how stake scales into vote weight)
+    pub stake_weighting: StakeWeighting,
+    pub stake_weight_cap: u64,
+
+    // Per-vouch limits (0 stake = unlimited, 10000 bps = unlimited)
+    pub max_stake_per_vouch: u64,
+    pub max_weight_share_bps: u16,
+
+    // Accuracy reputation (10000 floor = disabled)
+    pub accuracy_floor_bps: u16,
+    pub min_votes_for_accuracy: u32,
+
+    // Appeals
+    pub appeal_threshold: u16,
+    pub appeal_stake_multiplier: u8,
+    pub appeal_slash_bps: u16,
+    pub appeal_window: i64,
+    pub appeal_oracle_only: bool,
+
+    // Linked identities
+    pub max_linked_per_primary: u16,
+
+    // Social recovery
+    pub recovery_threshold: u16,
+    pub recovery_stake_multiplier: u8,
 }
 
 impl GovernanceParams {
@@ -88,13 +140,38 @@ impl GovernanceParams {
         2 +  // primary_slash_bps
         2 +  // duplicate_slash_bps
         2 +  // sybil_slash_bps
+        2 +  // slash_winners_bps
+        2 +  // slash_treasury_bps
+        2 +  // slash_burn_bps
+        8 +  // claim_fee_lamports
+        8 +  // resolution_bounty_lamports
+        4 +  // resolution_bounty_min_votes
+        8 +  // voting_reward
+        8 +  // stake_unbonding_period
+        8 +  // min_oracle_bond
+        2 +  // oracle_bond_slash_bps
+        2 +  // jury_selection_bps
         8 +  // primary_vote_weight
         8 +  // oracle_vote_weight
         8 +  // failed_claim_cooldown
         8 +  // duplicate_flag_cooldown
         8 +  // claim_expiry_duration
         2 +  // oracle_decay_rate_bps
-        2;   // admin_decay_rate_bps
+        2 +  // admin_decay_rate_bps
+        1 +  // stake_weighting
+        8 +  // stake_weight_cap
+        8 +  // max_stake_per_vouch
+        2 +  // max_weight_share_bps
+        2 +  // accuracy_floor_bps
+        4 +  // min_votes_for_accuracy
+        2 +  // appeal_threshold
+        1 +  // appeal_stake_multiplier
+        2 +  // appeal_slash_bps
+        8 +  // appeal_window
+        1 +  // appeal_oracle_only
+        2 +  // max_linked_per_primary
+        2 +  // recovery_threshold
+        1;   // recovery_stake_multiplier
 
     pub fn default() -> Self {
         Self {
@@ -108,6 +185,17 @@ impl GovernanceParams {
             primary_slash_bps: DEFAULT_PRIMARY_SLASH_BPS,
             duplicate_slash_bps: DEFAULT_DUPLICATE_SLASH_BPS,
             sybil_slash_bps: DEFAULT_SYBIL_SLASH_BPS,
+            slash_winners_bps: DEFAULT_SLASH_WINNERS_BPS,
+            slash_treasury_bps: DEFAULT_SLASH_TREASURY_BPS,
+            slash_burn_bps: DEFAULT_SLASH_BURN_BPS,
+            claim_fee_lamports: DEFAULT_CLAIM_FEE_LAMPORTS,
+            resolution_bounty_lamports: DEFAULT_RESOLUTION_BOUNTY_LAMPORTS,
+            resolution_bounty_min_votes: DEFAULT_RESOLUTION_BOUNTY_MIN_VOTES,
+            voting_reward: DEFAULT_VOTING_REWARD,
+            stake_unbonding_period: DEFAULT_STAKE_UNBONDING_PERIOD,
+            min_oracle_bond: DEFAULT_MIN_ORACLE_BOND,
+            oracle_bond_slash_bps: DEFAULT_ORACLE_BOND_SLASH_BPS,
+            jury_selection_bps: DEFAULT_JURY_SELECTION_BPS,
             primary_vote_weight: DEFAULT_PRIMARY_VOTE_WEIGHT,
             oracle_vote_weight: DEFAULT_ORACLE_VOTE_WEIGHT,
             failed_claim_cooldown: DEFAULT_FAILED_CLAIM_COOLDOWN,
@@ -115,6 +203,133 @@ impl GovernanceParams {
             claim_expiry_duration: DEFAULT_CLAIM_EXPIRY_DURATION,
             oracle_decay_rate_bps: DEFAULT_ORACLE_DECAY_RATE_BPS,
             admin_decay_rate_bps: DEFAULT_ADMIN_DECAY_RATE_BPS,
+            stake_weighting: StakeWeighting::Linear,
+            stake_weight_cap: DEFAULT_STAKE_WEIGHT_CAP_LAMPORTS,
+            max_stake_per_vouch: DEFAULT_MAX_STAKE_PER_VOUCH,
+            max_weight_share_bps: DEFAULT_MAX_WEIGHT_SHARE_BPS,
+            accuracy_floor_bps: DEFAULT_ACCURACY_FLOOR_BPS,
+            min_votes_for_accuracy: DEFAULT_MIN_VOTES_FOR_ACCURACY,
+            appeal_threshold: DEFAULT_APPEAL_THRESHOLD,
+            appeal_stake_multiplier: DEFAULT_APPEAL_STAKE_MULTIPLIER,
+            appeal_slash_bps: DEFAULT_APPEAL_SLASH_BPS,
+            appeal_window: DEFAULT_APPEAL_WINDOW,
+            appeal_oracle_only: false,
+            max_linked_per_primary: DEFAULT_MAX_LINKED_PER_PRIMARY,
+            recovery_threshold: DEFAULT_RECOVERY_THRESHOLD,
+            recovery_stake_multiplier: DEFAULT_RECOVERY_STAKE_MULTIPLIER,
+        }
+    }
+}
+
+/// How a voucher's KNOW stake is scaled before being multiplied by tier weight
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StakeWeighting {
+    #[default]
+    Linear,       // weight * stake
+    SquareRoot,   // weight * sqrt(stake)
+    Logarithmic,  // weight * log2(stake + 1), 16-bit fixed point
+    Capped,       // weight * min(stake, stake_weight_cap)
+}
+
+impl StakeWeighting {
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            StakeWeighting::Linear => 0,
+            StakeWeighting::SquareRoot => 1,
+            StakeWeighting::Logarithmic => 2,
+            StakeWeighting::Capped => 3,
+        }
+    }
+
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(StakeWeighting::Linear),
+            1 => Some(StakeWeighting::SquareRoot),
+            2 => Some(StakeWeighting::Logarithmic),
+            3 => Some(StakeWeighting::Capped),
+            _ => None,
+        }
+    }
+
+    /// Effective stake used in the weighted vote (`cap` only applies to `Capped`)
+    pub fn effective_stake(&self, stake: u64, cap: u64) -> u128 {
+        match self {
+            StakeWeighting::Linear => stake as u128,
+            StakeWeighting::SquareRoot => integer_sqrt(stake as u128),
+            StakeWeighting::Logarithmic => fixed_point_log2(stake.saturating_add(1)),
+            StakeWeighting::Capped => stake.min(cap) as u128,
         }
     }
 }
+
+/// Floor of the square root (Newton's method)
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// log2(value) in 16.16 fixed point, linearly interpolated between powers of two
+fn fixed_point_log2(value: u64) -> u128 {
+    if value == 0 {
+        return 0;
+    }
+    let int_part = 63 - value.leading_zeros() as u128;
+    let remainder = (value as u128) - (1u128 << int_part);
+    let frac_part = (remainder << 16) >> int_part;
+    (int_part << 16) | frac_part
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_sqrt_boundaries() {
+        assert_eq!(integer_sqrt(0), 0);
+        assert_eq!(integer_sqrt(1), 1);
+        assert_eq!(integer_sqrt(2), 1);
+        assert_eq!(integer_sqrt(3), 1);
+        assert_eq!(integer_sqrt(4), 2);
+        assert_eq!(integer_sqrt(15), 3);
+        assert_eq!(integer_sqrt(16), 4);
+        assert_eq!(integer_sqrt(u64::MAX as u128), u32::MAX as u128);
+        assert_eq!(integer_sqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn fixed_point_log2_boundaries() {
+        assert_eq!(fixed_point_log2(0), 0);
+        assert_eq!(fixed_point_log2(1), 0);
+        assert_eq!(fixed_point_log2(2), 1 << 16);
+        // Halfway between 2 and 4 interpolates to 1.5
+        assert_eq!(fixed_point_log2(3), (1 << 16) | (1 << 15));
+        assert_eq!(fixed_point_log2(1 << 40), 40 << 16);
+        // Fraction rounds down just below the next power of two
+        assert_eq!(fixed_point_log2(u64::MAX), (63 << 16) | 0xFFFF);
+    }
+
+    #[test]
+    fn effective_stake_by_mode() {
+        assert_eq!(StakeWeighting::Linear.effective_stake(u64::MAX, 0), u64::MAX as u128);
+        assert_eq!(StakeWeighting::SquareRoot.effective_stake(0, 0), 0);
+        assert_eq!(StakeWeighting::SquareRoot.effective_stake(100, 0), 10);
+        // log2(0 + 1) = 0, and u64::MAX does not overflow when adding 1
+        assert_eq!(StakeWeighting::Logarithmic.effective_stake(0, 0), 0);
+        assert_eq!(
+            StakeWeighting::Logarithmic.effective_stake(u64::MAX, 0),
+            fixed_point_log2(u64::MAX)
+        );
+        assert_eq!(StakeWeighting::Capped.effective_stake(500, 0), 0);
+        assert_eq!(StakeWeighting::Capped.effective_stake(500, 100), 100);
+        assert_eq!(StakeWeighting::Capped.effective_stake(50, 100), 50);
+        assert!(StakeWeighting::default() == StakeWeighting::Linear);
+    }
+}
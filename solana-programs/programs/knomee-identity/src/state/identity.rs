@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::BASIS_POINTS;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum IdentityTier {
@@ -37,6 +38,11 @@ impl IdentityTier {
     pub fn can_vote(&self) -> bool {
         matches!(self, IdentityTier::PrimaryID | IdentityTier::Oracle)
     }
+
+    /// Whether this tier is represented by a soul-bound identity token
+    pub fn has_identity_token(&self) -> bool {
+        !matches!(self, IdentityTier::GreyGhost)
+    }
 }
 
 #[account]
@@ -75,6 +81,39 @@ pub struct Identity {
     /// Timestamp of last failed claim (for cooldown enforcement)
     pub last_failed_claim_at: i64,
 
+    /// Votes cast on claims that resolved Approved or Rejected
+    pub votes_cast: u64,
+
+    /// Votes cast on the winning side
+    pub votes_won: u64,
+
+    /// Total KNOW returned/won from winning votes
+    pub stake_won: u64,
+
+    /// Total KNOW slashed from losing votes
+    pub stake_slashed: u64,
+
+    /// Tier currently represented by the owner's identity token (GreyGhost = none)
+    pub token_tier: IdentityTier,
+
+    /// Whether the one-time Primary verification KNOW reward has been paid
+    pub primary_reward_claimed: bool,
+
+    /// Last UBI epoch this identity claimed (0 = never)
+    pub last_ubi_epoch: u64,
+
+    /// Identity this Primary delegates its vote to (default = not delegating)
+    pub delegated_to: Pubkey,
+
+    /// When the last delegation was revoked (claims opened before this stay delegated)
+    pub delegation_ended_at: i64,
+
+    /// Active claims naming this identity as their subject
+    pub open_claims: u32,
+
+    /// When the latest appealable claim naming this identity as subject was resolved
+    pub last_claim_resolved_at: i64,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -92,15 +131,123 @@ impl Identity {
         8 +  // oracle_decay_start
         2 +  // linked_count
         8 +  // last_failed_claim_at
+        8 +  // votes_cast
+        8 +  // votes_won
+        8 +  // stake_won
+        8 +  // stake_slashed
+        1 +  // token_tier
+        1 +  // primary_reward_claimed
+        8 +  // last_ubi_epoch
+        32 + // delegated_to
+        8 +  // delegation_ended_at
+        4 +  // open_claims
+        8 +  // last_claim_resolved_at
         1;   // bump
 
     pub fn voting_weight(&self, params: &crate::state::GovernanceParams) -> u64 {
-        match self.tier {
+        let base_weight = match self.tier {
             IdentityTier::GreyGhost => 0,
             IdentityTier::LinkedID => 0, // LinkedIDs cannot vote
             IdentityTier::PrimaryID => params.primary_vote_weight,
             IdentityTier::Oracle => params.oracle_vote_weight,
+        };
+        if base_weight == 0 {
+            return 0;
+        }
+
+        // Scale by accuracy once there is enough history (floor of 10000 disables this)
+        let multiplier_bps = self.accuracy_multiplier_bps(params) as u128;
+        let scaled = (base_weight as u128 * multiplier_bps) / BASIS_POINTS as u128;
+        (scaled as u64).max(1)
+    }
+
+    /// Whether this is a LinkedID currently linked to `primary`
+    pub fn is_linked_to(&self, primary: &Pubkey) -> bool {
+        matches!(self.tier, IdentityTier::LinkedID) && self.primary_address == *primary
+    }
+
+    /// Share of decided votes cast on the winning side (basis points)
+    pub fn accuracy_bps(&self) -> u16 {
+        if self.votes_cast == 0 {
+            return BASIS_POINTS;
         }
+        let accuracy = (self.votes_won as u128 * BASIS_POINTS as u128)
+            / self.votes_cast as u128;
+        accuracy.min(BASIS_POINTS as u128) as u16
+    }
+
+    /// Voting weight multiplier interpolated between `accuracy_floor_bps` (0% accuracy)
+    /// and 10000 (100% accuracy)
+    pub fn accuracy_multiplier_bps(&self, params: &crate::state::GovernanceParams) -> u16 {
+        let floor = params.accuracy_floor_bps.min(BASIS_POINTS);
+        if self.votes_cast < params.min_votes_for_accuracy as u64 {
+            return BASIS_POINTS;
+        }
+        let range = (BASIS_POINTS - floor) as u32;
+        floor + ((range * self.accuracy_bps() as u32) / BASIS_POINTS as u32) as u16
+    }
+
+    /// Whether this is a freshly initialized identity with no verification or voting history
+    /// (failed claims are allowed so a rejected recovery can be retried after its cooldown)
+    pub fn is_blank(&self) -> bool {
+        matches!(self.tier, IdentityTier::GreyGhost)
+            && self.primary_address == self.owner
+            && self.verified_at == 0
+            && self.total_vouches_received == 0
+            && !self.under_challenge
+            && self.linked_count == 0
+            && self.votes_cast == 0
+            && self.open_claims == 0
+    }
+
+    /// Count a new claim naming this identity as its subject
+    pub fn claim_opened(&mut self) -> Option<()> {
+        self.open_claims = self.open_claims.checked_add(1)?;
+        Some(())
+    }
+
+    /// Stop counting a resolved claim, remembering when it resolved if it can be appealed
+    pub fn claim_resolved(&mut self, appealable: bool, current_time: i64) {
+        self.open_claims = self.open_claims.saturating_sub(1);
+        if appealable {
+            self.last_claim_resolved_at = current_time;
+        }
+    }
+
+    /// Whether a claim naming this identity as subject is active or still appealable.
+    /// Resolving such a claim needs this Identity, so it cannot be migrated or recovered yet.
+    pub fn has_open_claims(&self, appeal_window: i64, current_time: i64) -> bool {
+        self.open_claims > 0
+            || (self.last_claim_resolved_at > 0
+                && current_time <= self.last_claim_resolved_at.saturating_add(appeal_window))
+    }
+
+    /// Copy tier, stats and links onto `target` (which keeps its owner, bump, token tier and
+    /// claim counters). A delegation stays with the old key's `Delegation` record, so the
+    /// target starts undelegated, with claims opened while delegated still off-limits.
+    pub fn transfer_state_to(&self, target: &mut Identity, current_time: i64) {
+        target.tier = self.tier;
+        target.primary_address = target.owner;
+        target.verified_at = self.verified_at;
+        target.total_vouches_received = self.total_vouches_received;
+        target.total_stake_received = self.total_stake_received;
+        target.under_challenge = self.under_challenge;
+        target.challenge_claim_id = self.challenge_claim_id;
+        target.oracle_decay_start = self.oracle_decay_start;
+        target.linked_count = self.linked_count;
+        target.last_failed_claim_at = self.last_failed_claim_at;
+        target.votes_cast = self.votes_cast;
+        target.votes_won = self.votes_won;
+        target.stake_won = self.stake_won;
+        target.stake_slashed = self.stake_slashed;
+        target.primary_reward_claimed = self.primary_reward_claimed;
+        target.last_ubi_epoch = self.last_ubi_epoch;
+        target.delegated_to = Pubkey::default();
+        target.delegation_ended_at = if self.delegated_to != Pubkey::default() {
+            current_time
+        } else {
+            self.delegation_ended_at
+        };
     }
 
     pub fn is_primary(&self) -> bool {
@@ -112,7 +259,7 @@ impl Identity {
     }
 }
 
-/// Linked identity record (PDA derived from primary address + platform)
+/// Linked identity record (PDA derived from primary address + platform + index)
 #[account]
 #[derive(Default)]
 pub struct LinkedIdentity {
@@ -125,6 +272,9 @@ pub struct LinkedIdentity {
     /// Platform name (e.g., "LinkedIn", "Instagram", "GitHub-work")
     pub platform: String,
 
+    /// Ordinal of this account among the Primary's links on the same platform
+    pub index: u16,
+
     /// When this link was established
     pub linked_at: i64,
 
@@ -139,7 +289,127 @@ impl LinkedIdentity {
         32 + // primary_address
         32 + // linked_address
         4 + platform_len + // platform (String with length prefix)
+        2 +  // index
         8 +  // linked_at
         1    // bump
     }
+
+    /// Derive the record address for a Primary's `index`-th account on `platform`
+    pub fn find_address(primary: &Pubkey, platform: &str, index: u16) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                crate::constants::LINKED_IDENTITY_SEED,
+                primary.as_ref(),
+                platform.as_bytes(),
+                &index.to_le_bytes(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    /// Derive every record address a Primary can use on `platform` (indices
+    /// `0..MAX_LINK_INDEX`). Unlinking leaves gaps, so callers must skip missing accounts.
+    pub fn find_addresses(primary: &Pubkey, platform: &str) -> Vec<Pubkey> {
+        (0..crate::constants::MAX_LINK_INDEX)
+            .map(|index| Self::find_address(primary, platform, index).0)
+            .collect()
+    }
+}
+
+/// Reverse lookup from a linked (secondary) address to its Primary (PDA derived from linked address)
+#[account]
+#[derive(Default)]
+pub struct LinkedAddressLookup {
+    /// Linked (secondary) address
+    pub linked_address: Pubkey,
+
+    /// Primary address the linked address belongs to
+    pub primary_address: Pubkey,
+
+    /// Platform of the covering `LinkedIdentity` record
+    pub platform: String,
+
+    /// Index of the covering `LinkedIdentity` record
+    pub index: u16,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl LinkedAddressLookup {
+    // Sized for the longest platform name so relinking can update in place
+    pub const LEN: usize = 8 + // discriminator
+        32 + // linked_address
+        32 + // primary_address
+        4 + crate::constants::MAX_PLATFORM_NAME_LEN + // platform
+        2 +  // index
+        1;   // bump
+
+    /// Derive the lookup address for a linked address
+    pub fn find_address(linked_address: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[crate::constants::LINKED_ADDRESS_SEED, linked_address.as_ref()],
+            &crate::ID,
+        )
+    }
+
+    /// Address of the `LinkedIdentity` record covering this linked address
+    pub fn linked_identity_record(&self) -> Pubkey {
+        LinkedIdentity::find_address(&self.primary_address, &self.platform, self.index).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GovernanceParams;
+
+    fn params(floor_bps: u16, min_votes: u32) -> GovernanceParams {
+        GovernanceParams {
+            accuracy_floor_bps: floor_bps,
+            min_votes_for_accuracy: min_votes,
+            ..GovernanceParams::default()
+        }
+    }
+
+    fn voter(votes_won: u64, votes_cast: u64) -> Identity {
+        Identity {
+            votes_won,
+            votes_cast,
+            ..Identity::default()
+        }
+    }
+
+    #[test]
+    fn accuracy_ignored_below_min_votes() {
+        let params = params(0, 10);
+        assert_eq!(voter(0, 0).accuracy_multiplier_bps(&params), BASIS_POINTS);
+        assert_eq!(voter(0, 9).accuracy_multiplier_bps(&params), BASIS_POINTS);
+        assert_eq!(voter(0, 10).accuracy_multiplier_bps(&params), 0);
+    }
+
+    #[test]
+    fn accuracy_interpolates_from_floor() {
+        let params = params(2000, 1);
+        assert_eq!(voter(0, 1).accuracy_multiplier_bps(&params), 2000);
+        assert_eq!(voter(1, 2).accuracy_multiplier_bps(&params), 6000);
+        assert_eq!(voter(1, 1).accuracy_multiplier_bps(&params), BASIS_POINTS);
+        // 1/3 accuracy is 3333 bps; 2000 + 8000 * 3333 / 10000 rounds down
+        assert_eq!(voter(1, 3).accuracy_multiplier_bps(&params), 4666);
+    }
+
+    #[test]
+    fn accuracy_floor_at_or_above_full_disables_scaling() {
+        assert_eq!(voter(0, 100).accuracy_multiplier_bps(&params(10000, 1)), BASIS_POINTS);
+        assert_eq!(voter(0, 100).accuracy_multiplier_bps(&params(u16::MAX, 1)), BASIS_POINTS);
+    }
+
+    #[test]
+    fn accuracy_handles_extreme_counts() {
+        let params = params(0, 0);
+        assert_eq!(voter(u64::MAX, u64::MAX).accuracy_multiplier_bps(&params), BASIS_POINTS);
+        assert_eq!(voter(1, u64::MAX).accuracy_multiplier_bps(&params), 0);
+        // More wins than votes is clamped to 100%
+        assert_eq!(voter(5, 1).accuracy_bps(), BASIS_POINTS);
+    }
 }
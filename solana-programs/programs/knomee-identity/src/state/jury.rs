@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::BASIS_POINTS;
+
+/// Randomly drawn jury for a claim (PDA derived from claim ID).
+/// A voter is a juror when `hash(seed, voter)` falls under `selection_bps`.
+#[account]
+#[derive(Default)]
+pub struct Jury {
+    /// Claim this jury votes on
+    pub claim_id: u64,
+
+    /// Randomness seed (from the slot hash of `source_slot` and the claim ID)
+    pub seed: [u8; 32],
+
+    /// Share of eligible voters selected (basis points)
+    pub selection_bps: u16,
+
+    /// Slot whose hash seeded the jury
+    pub source_slot: u64,
+
+    /// When the jury was drawn
+    pub drawn_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Jury {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // claim_id
+        32 + // seed
+        2 +  // selection_bps
+        8 +  // source_slot
+        8 +  // drawn_at
+        1;   // bump
+
+    pub fn is_juror(&self, voter: &Pubkey) -> bool {
+        let digest = hashv(&[&self.seed, voter.as_ref()]).to_bytes();
+        // 64 bits keep the modulo bias negligible (16 bits would favour low draws)
+        let draw = u64::from_le_bytes(digest[..8].try_into().unwrap()) % BASIS_POINTS as u64;
+        draw < self.selection_bps as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jury(selection_bps: u16) -> Jury {
+        Jury {
+            seed: [7u8; 32],
+            selection_bps,
+            ..Jury::default()
+        }
+    }
+
+    fn voters(count: u8) -> impl Iterator<Item = Pubkey> {
+        (0..count).map(|i| Pubkey::new_from_array([i; 32]))
+    }
+
+    #[test]
+    fn empty_jury_selects_no_one() {
+        let jury = jury(0);
+        assert!(voters(200).all(|voter| !jury.is_juror(&voter)));
+    }
+
+    #[test]
+    fn full_jury_selects_everyone() {
+        let jury = jury(BASIS_POINTS);
+        assert!(voters(200).all(|voter| jury.is_juror(&voter)));
+    }
+
+    #[test]
+    fn selection_is_deterministic_and_monotonic() {
+        for voter in voters(200) {
+            assert_eq!(jury(5_000).is_juror(&voter), jury(5_000).is_juror(&voter));
+            // A juror at a lower rate stays a juror at any higher rate
+            if jury(1).is_juror(&voter) {
+                assert!(jury(5_000).is_juror(&voter));
+            }
+            if jury(5_000).is_juror(&voter) {
+                assert!(jury(BASIS_POINTS - 1).is_juror(&voter));
+            }
+        }
+    }
+
+    #[test]
+    fn half_rate_selects_roughly_half() {
+        let selected = voters(200).filter(|voter| jury(5_000).is_juror(voter)).count();
+        assert!((60..=140).contains(&selected), "selected {selected} of 200");
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Record of an identity moved to a new key (PDA derived from the old owner).
+/// Its existence revokes the old key: it can no longer initialize an identity.
+#[account]
+#[derive(Default)]
+pub struct IdentityMigration {
+    /// Revoked (previous) owner
+    pub old_owner: Pubkey,
+
+    /// Owner the identity was moved to
+    pub new_owner: Pubkey,
+
+    /// When the migration happened
+    pub migrated_at: i64,
+
+    /// Recovery claim that authorized the migration (0 if done by the old owner)
+    pub recovery_claim_id: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl IdentityMigration {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // old_owner
+        32 + // new_owner
+        8 +  // migrated_at
+        8 +  // recovery_claim_id
+        1;   // bump
+}
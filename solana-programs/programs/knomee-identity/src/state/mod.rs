@@ -2,8 +2,28 @@ pub mod governance;
 pub mod identity;
 pub mod claim;
 pub mod vouch;
+pub mod platform;
+pub mod migration;
+pub mod recovery;
+pub mod rewards;
+pub mod ubi;
+pub mod treasury;
+pub mod stake;
+pub mod delegation;
+pub mod jury;
+pub mod evidence;
 
 pub use governance::*;
 pub use identity::*;
 pub use claim::*;
 pub use vouch::*;
+pub use platform::*;
+pub use migration::*;
+pub use recovery::*;
+pub use rewards::*;
+pub use ubi::*;
+pub use treasury::*;
+pub use stake::*;
+pub use delegation::*;
+pub use jury::*;
+pub use evidence::*;
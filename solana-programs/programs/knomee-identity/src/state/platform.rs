@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::KnomeeError};
+
+/// Governance-registered platform identifier (PDA derived from canonical platform name)
+#[account]
+#[derive(Default)]
+pub struct RegisteredPlatform {
+    /// Canonical platform name (see `normalize_platform`)
+    pub name: String,
+
+    /// Whether new links may use this platform
+    pub enabled: bool,
+
+    /// When the platform was registered
+    pub registered_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RegisteredPlatform {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_PLATFORM_NAME_LEN + // name
+        1 +  // enabled
+        8 +  // registered_at
+        1;   // bump
+}
+
+/// Canonical form of a platform name: trimmed, ASCII lowercase, limited to
+/// `a-z`, `0-9`, `-`, `_` and `.`
+pub fn normalize_platform(platform: &str) -> Result<String> {
+    let normalized = platform.trim().to_ascii_lowercase();
+
+    require!(!normalized.is_empty(), KnomeeError::InvalidPlatformName);
+    require!(
+        normalized.len() <= MAX_PLATFORM_NAME_LEN,
+        KnomeeError::PlatformNameTooLong
+    );
+    require!(
+        normalized
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'-' | b'_' | b'.')),
+        KnomeeError::InvalidPlatformName
+    );
+
+    Ok(normalized)
+}
+
+/// Require `platform` to already be in canonical form, since it is used raw in PDA seeds
+pub fn require_canonical_platform(platform: &str) -> Result<()> {
+    require!(
+        normalize_platform(platform)? == platform,
+        KnomeeError::PlatformNotCanonical
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_trims_and_lowercases() {
+        assert_eq!(normalize_platform("GitHub").unwrap(), "github");
+        assert_eq!(normalize_platform("  LinkedIn \t").unwrap(), "linkedin");
+        assert_eq!(normalize_platform("x.com").unwrap(), "x.com");
+        assert_eq!(normalize_platform("github-work_2").unwrap(), "github-work_2");
+    }
+
+    #[test]
+    fn normalize_rejects_empty_names() {
+        assert_eq!(
+            normalize_platform("").unwrap_err(),
+            KnomeeError::InvalidPlatformName.into()
+        );
+        assert_eq!(
+            normalize_platform("   ").unwrap_err(),
+            KnomeeError::InvalidPlatformName.into()
+        );
+    }
+
+    #[test]
+    fn normalize_length_boundary() {
+        let max = "a".repeat(MAX_PLATFORM_NAME_LEN);
+        assert_eq!(normalize_platform(&max).unwrap(), max);
+        // Surrounding whitespace does not count towards the limit
+        assert_eq!(normalize_platform(&format!(" {} ", max)).unwrap(), max);
+        assert_eq!(
+            normalize_platform(&"a".repeat(MAX_PLATFORM_NAME_LEN + 1)).unwrap_err(),
+            KnomeeError::PlatformNameTooLong.into()
+        );
+    }
+
+    #[test]
+    fn normalize_rejects_other_characters() {
+        for name in ["git hub", "git/hub", "gïthub", "github!", "ｇithub"] {
+            assert_eq!(
+                normalize_platform(name).unwrap_err(),
+                KnomeeError::InvalidPlatformName.into()
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_names_only() {
+        assert!(require_canonical_platform("github").is_ok());
+        assert_eq!(
+            require_canonical_platform("GitHub").unwrap_err(),
+            KnomeeError::PlatformNotCanonical.into()
+        );
+        assert_eq!(
+            require_canonical_platform("github ").unwrap_err(),
+            KnomeeError::PlatformNotCanonical.into()
+        );
+    }
+}
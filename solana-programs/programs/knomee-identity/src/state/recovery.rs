@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::constants::{GUARDIAN_PROPOSAL_EXPIRY, MAX_GUARDIANS};
+
+/// Guardian-based recovery settings for an identity (PDA derived from owner)
+#[account]
+#[derive(Default)]
+pub struct RecoveryConfig {
+    /// Identity owner this config protects
+    pub owner: Pubkey,
+
+    /// Guardian addresses (up to MAX_GUARDIANS)
+    pub guardians: Vec<Pubkey>,
+
+    /// Guardian approvals required (M of N)
+    pub threshold: u8,
+
+    /// Seconds the owner has to veto once the threshold is reached
+    pub veto_delay: i64,
+
+    /// Proposed new owner (default pubkey if no proposal is pending)
+    pub proposed_owner: Pubkey,
+
+    /// When the pending proposal was opened
+    pub proposed_at: i64,
+
+    /// Bitmap of approving guardians (bit i = guardians[i])
+    pub approvals: u16,
+
+    /// When the approval threshold was reached (0 if not yet)
+    pub approved_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RecoveryConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        4 + 32 * MAX_GUARDIANS + // guardians
+        1 +  // threshold
+        8 +  // veto_delay
+        32 + // proposed_owner
+        8 +  // proposed_at
+        2 +  // approvals
+        8 +  // approved_at
+        1;   // bump
+
+    pub fn has_pending_proposal(&self) -> bool {
+        self.proposed_owner != Pubkey::default()
+    }
+
+    pub fn guardian_index(&self, guardian: &Pubkey) -> Option<usize> {
+        self.guardians.iter().position(|g| g == guardian)
+    }
+
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+
+    /// Whether the threshold is met and the owner's veto window has passed
+    pub fn is_executable(&self, current_time: i64) -> bool {
+        self.has_pending_proposal()
+            && self.approved_at > 0
+            && current_time >= self.approved_at.saturating_add(self.veto_delay)
+    }
+
+    /// Whether a pending proposal failed to reach the threshold within `GUARDIAN_PROPOSAL_EXPIRY`
+    /// (guardians may then replace it, so one guardian cannot block recovery)
+    pub fn is_proposal_stale(&self, current_time: i64) -> bool {
+        self.has_pending_proposal()
+            && self.approved_at == 0
+            && current_time >= self.proposed_at.saturating_add(GUARDIAN_PROPOSAL_EXPIRY)
+    }
+
+    pub fn clear_proposal(&mut self) {
+        self.proposed_owner = Pubkey::default();
+        self.proposed_at = 0;
+        self.approvals = 0;
+        self.approved_at = 0;
+    }
+}
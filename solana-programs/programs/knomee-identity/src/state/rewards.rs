@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// KNOW rewards pool (singleton PDA). The pool PDA is both the KNOW mint authority
+/// and the owner of the rewards vault that Primary, Oracle and voting rewards are paid from.
+#[account]
+#[derive(Default)]
+pub struct RewardsPool {
+    /// KNOW token mint (mint authority must be this PDA)
+    pub know_mint: Pubkey,
+
+    /// Token account holding undistributed rewards
+    pub vault: Pubkey,
+
+    /// Launch time, start of the early adopter period
+    pub launched_at: i64,
+
+    /// Total KNOW minted into the vault
+    pub total_funded: u64,
+
+    /// Total KNOW paid out as rewards
+    pub total_rewards_paid: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RewardsPool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // know_mint
+        32 + // vault
+        8 +  // launched_at
+        8 +  // total_funded
+        8 +  // total_rewards_paid
+        1;   // bump
+
+    pub fn is_early_adopter_period(&self, current_time: i64) -> bool {
+        current_time < self.launched_at.saturating_add(EARLY_ADOPTER_PERIOD)
+    }
+
+    /// Apply the early adopter multiplier to a base reward
+    pub fn reward_amount(&self, base_reward: u64, current_time: i64) -> Option<u64> {
+        if self.is_early_adopter_period(current_time) {
+            base_reward.checked_mul(EARLY_ADOPTER_MULTIPLIER as u64)
+        } else {
+            Some(base_reward)
+        }
+    }
+}
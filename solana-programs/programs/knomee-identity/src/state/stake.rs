@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use crate::constants::BASIS_POINTS;
+
+/// Per-user KNOW staking balance (PDA derived from owner). Tokens are held in the
+/// program stake escrow; this account is the owner's ledger over that escrow.
+#[account]
+#[derive(Default)]
+pub struct StakeAccount {
+    /// Owner of the deposited KNOW
+    pub owner: Pubkey,
+
+    /// Total KNOW credited to the owner (includes locked and unbonding)
+    pub deposited: u64,
+
+    /// KNOW locked by active vouches
+    pub locked: u64,
+
+    /// KNOW waiting out the unbonding period
+    pub unbonding: u64,
+
+    /// KNOW bonded to hold Oracle tier (slashable, not usable for vouches)
+    pub bonded: u64,
+
+    /// When unbonding KNOW becomes withdrawable
+    pub unbonding_available_at: i64,
+
+    /// Number of vouches currently locking stake
+    pub active_vouches: u32,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl StakeAccount {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        8 +  // deposited
+        8 +  // locked
+        8 +  // unbonding
+        8 +  // bonded
+        8 +  // unbonding_available_at
+        4 +  // active_vouches
+        1;   // bump
+
+    /// KNOW that can be locked by a vouch or moved into unbonding
+    pub fn available(&self) -> u64 {
+        self.deposited
+            .saturating_sub(self.locked)
+            .saturating_sub(self.unbonding)
+            .saturating_sub(self.bonded)
+    }
+
+    /// Lock stake for a new vouch
+    pub fn lock(&mut self, amount: u64) -> Option<()> {
+        if amount > self.available() {
+            return None;
+        }
+        self.locked = self.locked.checked_add(amount)?;
+        self.active_vouches = self.active_vouches.checked_add(1)?;
+        Some(())
+    }
+
+    /// Release a vouch's lock, removing `slashed` KNOW from the balance and crediting `earned`
+    pub fn settle(&mut self, stake: u64, slashed: u64, earned: u64) -> Option<()> {
+        self.locked = self.locked.checked_sub(stake)?;
+        self.active_vouches = self.active_vouches.checked_sub(1)?;
+        self.deposited = self.deposited.checked_sub(slashed)?.checked_add(earned)?;
+        Some(())
+    }
+
+    /// Slash `bps` of the Oracle bond, returning the amount removed
+    pub fn slash_bond(&mut self, bps: u16) -> Option<u64> {
+        let amount = ((self.bonded as u128) * (bps as u128) / (BASIS_POINTS as u128)) as u64;
+        self.bonded = self.bonded.checked_sub(amount)?;
+        self.deposited = self.deposited.checked_sub(amount)?;
+        Some(amount)
+    }
+
+    /// Move the whole Oracle bond onto `target`, returning the amount moved (both
+    /// balances are held in the shared escrow, so no tokens move)
+    pub fn transfer_bond_to(&mut self, target: &mut StakeAccount) -> Option<u64> {
+        let amount = self.bonded;
+        self.deposited = self.deposited.checked_sub(amount)?;
+        self.bonded = 0;
+        target.deposited = target.deposited.checked_add(amount)?;
+        target.bonded = target.bonded.checked_add(amount)?;
+        Some(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stake(deposited: u64, locked: u64, unbonding: u64, bonded: u64) -> StakeAccount {
+        StakeAccount {
+            deposited,
+            locked,
+            unbonding,
+            bonded,
+            ..StakeAccount::default()
+        }
+    }
+
+    #[test]
+    fn available_excludes_locked_unbonding_and_bonded() {
+        assert_eq!(stake(0, 0, 0, 0).available(), 0);
+        assert_eq!(stake(100, 10, 20, 30).available(), 40);
+        assert_eq!(stake(u64::MAX, 0, 0, 0).available(), u64::MAX);
+        // Slashing can leave commitments above the balance; never underflows
+        assert_eq!(stake(10, u64::MAX, 1, 1).available(), 0);
+    }
+
+    #[test]
+    fn lock_bounded_by_available() {
+        let mut account = stake(100, 0, 0, 40);
+        assert_eq!(account.lock(61), None);
+        assert_eq!(account.locked, 0);
+
+        assert_eq!(account.lock(60), Some(()));
+        assert_eq!((account.locked, account.active_vouches), (60, 1));
+        assert_eq!(account.lock(1), None);
+
+        // A zero-stake lock still counts as an active vouch
+        assert_eq!(account.lock(0), Some(()));
+        assert_eq!(account.active_vouches, 2);
+    }
+
+    #[test]
+    fn settle_releases_lock_and_applies_outcome() {
+        let mut account = stake(100, 50, 0, 0);
+        account.active_vouches = 2;
+
+        // Winner: lock released, share credited
+        assert_eq!(account.settle(20, 0, 7), Some(()));
+        assert_eq!((account.deposited, account.locked, account.active_vouches), (107, 30, 1));
+
+        // Loser: lock released, slashed part removed
+        assert_eq!(account.settle(30, 3, 0), Some(()));
+        assert_eq!((account.deposited, account.locked, account.active_vouches), (104, 0, 0));
+    }
+
+    #[test]
+    fn settle_rejects_unbalanced_ledger() {
+        // No active vouch to release
+        assert_eq!(stake(100, 10, 0, 0).settle(10, 0, 0), None);
+
+        let mut account = stake(5, 10, 0, 0);
+        account.active_vouches = 1;
+        assert_eq!(account.clone().settle(11, 0, 0), None);
+        assert_eq!(account.clone().settle(10, 6, 0), None);
+        assert_eq!(account.clone().settle(10, 0, u64::MAX), None);
+        assert_eq!(account.settle(10, 5, 0), Some(()));
+        assert_eq!(account.deposited, 0);
+    }
+
+    #[test]
+    fn slash_bond_rounds_down() {
+        let mut account = stake(1_000, 0, 0, 999);
+        assert_eq!(account.slash_bond(0), Some(0));
+        assert_eq!(account.slash_bond(1), Some(0));
+        assert_eq!(account.slash_bond(5_000), Some(499));
+        assert_eq!((account.bonded, account.deposited), (500, 501));
+        assert_eq!(account.slash_bond(BASIS_POINTS), Some(500));
+        assert_eq!((account.bonded, account.deposited), (0, 1));
+
+        let mut max = stake(u64::MAX, 0, 0, u64::MAX);
+        assert_eq!(max.slash_bond(BASIS_POINTS), Some(u64::MAX));
+    }
+
+    #[test]
+    fn transfer_bond_moves_bonded_balance() {
+        let mut lost = stake(500, 0, 0, 300);
+        let mut recovered = stake(100, 0, 0, 50);
+        assert_eq!(lost.transfer_bond_to(&mut recovered), Some(300));
+        assert_eq!((lost.deposited, lost.bonded), (200, 0));
+        assert_eq!((recovered.deposited, recovered.bonded), (400, 350));
+
+        assert_eq!(stake(10, 0, 0, 20).transfer_bond_to(&mut stake(0, 0, 0, 0)), None);
+        assert_eq!(stake(10, 0, 0, 10).transfer_bond_to(&mut stake(u64::MAX, 0, 0, 0)), None);
+    }
+}
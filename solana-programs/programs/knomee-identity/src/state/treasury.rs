@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Protocol treasury record (singleton PDA). The vault it points to is owned by the
+/// governance PDA and receives claim fees and the treasury share of slashed stake.
+#[account]
+#[derive(Default)]
+pub struct Treasury {
+    /// Treasury vault token account
+    pub vault: Pubkey,
+
+    /// Program-owned escrow holding claim and vouch stakes
+    pub stake_escrow: Pubkey,
+
+    /// Total KNOW withdrawn by governance
+    pub total_withdrawn: u64,
+
+    /// Number of governance withdrawals
+    pub withdrawal_count: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // vault
+        32 + // stake_escrow
+        8 +  // total_withdrawn
+        8 +  // withdrawal_count
+        1;   // bump
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+/// UBI distribution pool (singleton PDA). Owns the UBI vault and tracks the current epoch.
+#[account]
+#[derive(Default)]
+pub struct UbiPool {
+    /// KNOW token mint distributed as UBI
+    pub know_mint: Pubkey,
+
+    /// Token account holding UBI funds (authority = this PDA)
+    pub vault: Pubkey,
+
+    /// Number of the latest epoch (0 = none started)
+    pub current_epoch: u64,
+
+    /// When the latest epoch's claim window closes
+    pub current_epoch_ends_at: i64,
+
+    /// Total KNOW distributed across all epochs
+    pub total_distributed: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl UbiPool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // know_mint
+        32 + // vault
+        8 +  // current_epoch
+        8 +  // current_epoch_ends_at
+        8 +  // total_distributed
+        1;   // bump
+}
+
+/// A UBI distribution epoch (PDA derived from epoch number)
+#[account]
+#[derive(Default)]
+pub struct Epoch {
+    /// Sequential epoch number (starting at 1)
+    pub epoch_number: u64,
+
+    /// KNOW paid to each eligible identity that claims
+    pub per_capita_amount: u64,
+
+    /// Claim window start
+    pub starts_at: i64,
+
+    /// Claim window end
+    pub ends_at: i64,
+
+    /// Number of identities that claimed
+    pub claimant_count: u64,
+
+    /// Total KNOW claimed this epoch
+    pub total_claimed: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Epoch {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // epoch_number
+        8 +  // per_capita_amount
+        8 +  // starts_at
+        8 +  // ends_at
+        8 +  // claimant_count
+        8 +  // total_claimed
+        1;   // bump
+
+    pub fn is_active(&self, current_time: i64) -> bool {
+        current_time >= self.starts_at && current_time < self.ends_at
+    }
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::ORACLE_REWARD_PER_CLAIM;
+use crate::state::{ClaimStatus, GovernanceParams, IdentityClaim};
 
 #[account]
 pub struct Vouch {
@@ -11,9 +13,12 @@ pub struct Vouch {
     /// Whether supporting (true = FOR, false = AGAINST)
     pub supports: bool,
 
-    /// Voting weight at time of vouch
+    /// Voting weight at time of vouch (including delegated weight)
     pub weight: u64,
 
+    /// Portion of `weight` delegated by other Primaries
+    pub delegated_weight: u64,
+
     /// Amount of KNOW staked
     pub stake: u64,
 
@@ -26,26 +31,74 @@ pub struct Vouch {
     /// Calculated reward amount (set during resolution)
     pub reward_amount: u64,
 
+    /// Weighted vote counted on the claim
+    pub counted_vote: u128,
+
+    /// Whether the KNOW voting (and Oracle) reward has been paid from the rewards pool
+    pub know_reward_paid: bool,
+
+    /// Whether the voucher was an Oracle when voting (earns the Oracle reward)
+    pub voted_as_oracle: bool,
+
+    /// Hash of an off-chain rationale document (zero if none)
+    pub rationale_hash: [u8; 32],
+
+    /// Short on-chain rationale for the vote (empty if none)
+    pub rationale: String,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
 
 impl Vouch {
-    pub const LEN: usize = 8 +  // discriminator
+    // Dynamic size based on rationale length (grown by `set_vouch_rationale`)
+    pub fn space(rationale_len: usize) -> usize {
+        8 +  // discriminator
         8 +  // claim_id
         32 + // voucher
         1 +  // supports
         8 +  // weight
+        8 +  // delegated_weight
         8 +  // stake
         8 +  // vouched_at
         1 +  // rewards_claimed
         8 +  // reward_amount
-        1;   // bump
+        16 + // counted_vote
+        1 +  // know_reward_paid
+        1 +  // voted_as_oracle
+        32 + // rationale_hash
+        4 + rationale_len + // rationale (String with length prefix)
+        1    // bump
+    }
+
+    pub fn has_rationale(&self) -> bool {
+        !self.rationale.is_empty() || self.rationale_hash != [0u8; 32]
+    }
+
+    /// Calculate weighted vote contribution using the claim's stake weighting
+    pub fn weighted_vote(&self, claim: &IdentityClaim) -> u128 {
+        let effective_stake = claim
+            .stake_weighting
+            .effective_stake(self.stake, claim.stake_weight_cap);
+        (self.weight as u128).saturating_mul(effective_stake)
+    }
+
+    /// Whether this vouch sided with the claim's outcome
+    pub fn is_winner(&self, claim: &IdentityClaim) -> bool {
+        match claim.status {
+            ClaimStatus::Approved => self.supports,
+            ClaimStatus::Rejected => !self.supports,
+            _ => false,
+        }
+    }
 
-    /// Calculate weighted vote contribution
-    pub fn weighted_vote(&self) -> u128 {
-        (self.weight as u128)
-            .checked_mul(self.stake as u128)
-            .unwrap_or(u128::MAX)
+    /// KNOW voting reward owed before the early adopter multiplier: the governance-set
+    /// base reward, plus the Oracle reward if the voucher was an Oracle when voting
+    pub fn voting_reward(&self, claim: &IdentityClaim, params: &GovernanceParams) -> u64 {
+        if !self.is_winner(claim) {
+            return 0;
+        }
+        let oracle_reward = if self.voted_as_oracle { ORACLE_REWARD_PER_CLAIM } else { 0 };
+        params.voting_reward.saturating_add(oracle_reward)
     }
 }
@@ -0,0 +1,777 @@
+//! Minimal in-process runtime for instruction-level tests.
+//!
+//! Instructions run through the program's real entrypoint: accounts are serialized in the
+//! loader's input format, so Anchor's constraints, `init`, `close` and reallocation behave as
+//! on-chain. System program and SPL Token (incl. Token-2022) CPIs are emulated, and the Clock
+//! and Rent sysvars come from the test's `Env`.
+
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    entrypoint::{deserialize, MAX_PERMITTED_DATA_INCREASE},
+    instruction::Instruction,
+    program_error::ProgramError,
+    program_pack::Pack,
+    program_stubs::{self, SyscallStubs},
+    program_utils::limited_deserialize,
+    system_instruction::SystemInstruction,
+    system_program, sysvar,
+};
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use knomee_identity::{constants::*, errors::KnomeeError, state::*};
+
+pub const KNOW: u64 = 1_000_000_000;
+pub const START_TIME: i64 = 1_700_000_000;
+pub const START_SLOT: u64 = 1_000;
+
+#[derive(Clone, Debug)]
+pub struct TestAccount {
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+    pub executable: bool,
+}
+
+thread_local! {
+    static CLOCK: RefCell<Clock> = RefCell::new(Clock::default());
+    static EVENTS: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        EVENTS.with(|events| events.borrow_mut().push(fields.concat()));
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        CLOCK.with(|clock| unsafe { *(var_addr as *mut Clock) = clock.borrow().clone() });
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> std::result::Result<(), ProgramError> {
+        let account = |index: usize| -> std::result::Result<&AccountInfo, ProgramError> {
+            let key = instruction
+                .accounts
+                .get(index)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?
+                .pubkey;
+            account_infos
+                .iter()
+                .find(|info| *info.key == key)
+                .ok_or(ProgramError::NotEnoughAccountKeys)
+        };
+
+        if instruction.program_id == system_program::ID {
+            return match limited_deserialize(&instruction.data, 1024)
+                .map_err(|_| ProgramError::InvalidInstructionData)?
+            {
+                SystemInstruction::CreateAccount {
+                    lamports,
+                    space,
+                    owner,
+                } => {
+                    let (from, to) = (account(0)?, account(1)?);
+                    if to.lamports() > 0 || !to.data_is_empty() {
+                        return Err(ProgramError::Custom(0)); // AccountAlreadyInUse
+                    }
+                    move_lamports(from, to, lamports)?;
+                    to.realloc(space as usize, true)?;
+                    to.assign(&owner);
+                    Ok(())
+                }
+                SystemInstruction::Transfer { lamports } => {
+                    move_lamports(account(0)?, account(1)?, lamports)
+                }
+                SystemInstruction::Allocate { space } => {
+                    let target = account(0)?;
+                    if !target.data_is_empty() {
+                        return Err(ProgramError::Custom(0));
+                    }
+                    target.realloc(space as usize, true)
+                }
+                SystemInstruction::Assign { owner } => {
+                    account(0)?.assign(&owner);
+                    Ok(())
+                }
+                _ => Err(ProgramError::InvalidInstructionData),
+            };
+        }
+
+        if instruction.program_id == spl_token::ID || instruction.program_id == spl_token_2022::ID {
+            let amount = || -> std::result::Result<u64, ProgramError> {
+                instruction
+                    .data
+                    .get(1..9)
+                    .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+                    .ok_or(ProgramError::InvalidInstructionData)
+            };
+            return match instruction.data[0] {
+                // Transfer, TransferChecked
+                3 => move_tokens(account(0)?, Some(account(1)?), amount()?),
+                12 => move_tokens(account(0)?, Some(account(2)?), amount()?),
+                // MintTo, MintToChecked
+                7 | 14 => move_tokens_in(account(1)?, amount()?),
+                // Burn, BurnChecked
+                8 | 15 => move_tokens(account(0)?, None, amount()?),
+                // InitializeMint2: mark initialized; extension initializers are accepted as-is
+                20 => {
+                    account(0)?.try_borrow_mut_data()?[45] = 1;
+                    Ok(())
+                }
+                32 | 35 => Ok(()),
+                _ => Err(ProgramError::InvalidInstructionData),
+            };
+        }
+
+        Err(ProgramError::IncorrectProgramId)
+    }
+}
+
+fn move_lamports(
+    from: &AccountInfo,
+    to: &AccountInfo,
+    lamports: u64,
+) -> std::result::Result<(), ProgramError> {
+    let remaining = from
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
+
+const TOKEN_AMOUNT: std::ops::Range<usize> = 64..72;
+
+fn token_amount(info: &AccountInfo) -> std::result::Result<u64, ProgramError> {
+    let data = info.try_borrow_data()?;
+    Ok(u64::from_le_bytes(data[TOKEN_AMOUNT].try_into().unwrap()))
+}
+
+fn set_token_amount(info: &AccountInfo, amount: u64) -> std::result::Result<(), ProgramError> {
+    info.try_borrow_mut_data()?[TOKEN_AMOUNT].copy_from_slice(&amount.to_le_bytes());
+    Ok(())
+}
+
+fn move_tokens(
+    from: &AccountInfo,
+    to: Option<&AccountInfo>,
+    amount: u64,
+) -> std::result::Result<(), ProgramError> {
+    let remaining = token_amount(from)?
+        .checked_sub(amount)
+        .ok_or(ProgramError::Custom(1))?; // InsufficientFunds
+    set_token_amount(from, remaining)?;
+    match to {
+        Some(to) => move_tokens_in(to, amount),
+        None => Ok(()),
+    }
+}
+
+fn move_tokens_in(to: &AccountInfo, amount: u64) -> std::result::Result<(), ProgramError> {
+    let balance = token_amount(to)?
+        .checked_add(amount)
+        .ok_or(ProgramError::Custom(14))?;
+    set_token_amount(to, balance)
+}
+
+pub fn knomee_error(error: KnomeeError) -> ProgramError {
+    anchor_lang::error::Error::from(error).into()
+}
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &knomee_identity::ID).0
+}
+
+pub struct Env {
+    pub accounts: HashMap<Pubkey, TestAccount>,
+    pub clock: Clock,
+    pub know_mint: Pubkey,
+    pub payer: Pubkey,
+}
+
+impl Env {
+    pub fn new() -> Self {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+
+        let mut env = Env {
+            accounts: HashMap::new(),
+            clock: Clock {
+                slot: START_SLOT,
+                unix_timestamp: START_TIME,
+                ..Clock::default()
+            },
+            know_mint: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+        };
+        for program in [
+            knomee_identity::ID,
+            system_program::ID,
+            spl_token::ID,
+            spl_token_2022::ID,
+        ] {
+            env.accounts.insert(
+                program,
+                TestAccount {
+                    lamports: 1,
+                    owner: Pubkey::default(),
+                    data: vec![],
+                    executable: true,
+                },
+            );
+        }
+        env.fund(env.payer, 1_000 * KNOW);
+        env.put_mint(env.know_mint);
+        env.put_slot_hashes(&[]);
+        env
+    }
+
+    // ------------------------------------------------------------
+    // Account setup
+    // ------------------------------------------------------------
+
+    pub fn fund(&mut self, key: Pubkey, lamports: u64) {
+        self.accounts
+            .entry(key)
+            .or_insert(TestAccount {
+                lamports: 0,
+                owner: system_program::ID,
+                data: vec![],
+                executable: false,
+            })
+            .lamports += lamports;
+    }
+
+    pub fn new_wallet(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.fund(key, 100 * KNOW);
+        key
+    }
+
+    pub fn put_anchor<T: AccountSerialize>(&mut self, key: Pubkey, account: &T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                owner: knomee_identity::ID,
+                data,
+                executable: false,
+            },
+        );
+    }
+
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).expect("account does not exist");
+        T::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.accounts
+            .get(key)
+            .is_some_and(|account| account.lamports > 0)
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |account| account.lamports)
+    }
+
+    pub fn put_mint(&mut self, key: Pubkey) {
+        let mut data = vec![0u8; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            decimals: 9,
+            is_initialized: true,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports: KNOW,
+                owner: spl_token::ID,
+                data,
+                executable: false,
+            },
+        );
+    }
+
+    /// Create a token account (`token_program` is SPL Token or Token-2022)
+    pub fn put_token_account_for(
+        &mut self,
+        key: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        token_program: Pubkey,
+    ) {
+        let mut data = vec![0u8; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports: KNOW,
+                owner: token_program,
+                data,
+                executable: false,
+            },
+        );
+    }
+
+    pub fn put_token_account(&mut self, key: Pubkey, owner: Pubkey, amount: u64) {
+        self.put_token_account_for(key, self.know_mint, owner, amount, spl_token::ID);
+    }
+
+    pub fn new_token_account(&mut self, owner: Pubkey, amount: u64) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.put_token_account(key, owner, amount);
+        key
+    }
+
+    pub fn token_balance(&self, key: &Pubkey) -> u64 {
+        let data = &self.accounts[key].data;
+        u64::from_le_bytes(data[TOKEN_AMOUNT].try_into().unwrap())
+    }
+
+    /// Replace the SlotHashes sysvar with `(slot, hash)` entries, newest first
+    pub fn put_slot_hashes(&mut self, entries: &[(u64, [u8; 32])]) {
+        let mut data = (entries.len() as u64).to_le_bytes().to_vec();
+        for (slot, hash) in entries {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(hash);
+        }
+        self.accounts.insert(
+            sysvar::slot_hashes::ID,
+            TestAccount {
+                lamports: 1,
+                owner: sysvar::ID,
+                data,
+                executable: false,
+            },
+        );
+    }
+
+    pub fn warp(&mut self, seconds: i64, slots: u64) {
+        self.clock.unix_timestamp += seconds;
+        self.clock.slot += slots;
+    }
+
+    pub fn now(&self) -> i64 {
+        self.clock.unix_timestamp
+    }
+
+    pub fn take_events(&self) -> Vec<Vec<u8>> {
+        EVENTS.with(|events| std::mem::take(&mut *events.borrow_mut()))
+    }
+
+    // ------------------------------------------------------------
+    // Execution
+    // ------------------------------------------------------------
+
+    /// Run one program instruction; account changes are kept only if it succeeds
+    pub fn run(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+    ) -> std::result::Result<(), ProgramError> {
+        let instruction = Instruction {
+            program_id: knomee_identity::ID,
+            accounts: accounts.to_account_metas(None),
+            data: data.data(),
+        };
+        self.process(&instruction)
+    }
+
+    pub fn process(&mut self, instruction: &Instruction) -> std::result::Result<(), ProgramError> {
+        CLOCK.with(|clock| *clock.borrow_mut() = self.clock.clone());
+
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut flags: HashMap<Pubkey, (bool, bool)> = HashMap::new();
+        for meta in &instruction.accounts {
+            let entry = flags.entry(meta.pubkey).or_insert((false, false));
+            entry.0 |= meta.is_signer;
+            entry.1 |= meta.is_writable;
+            if !keys.contains(&meta.pubkey) {
+                keys.push(meta.pubkey);
+            }
+        }
+
+        let mut input = self.serialize(instruction, &flags);
+        let (program_id, infos, data) = unsafe { deserialize(input.as_mut_ptr() as *mut u8) };
+        let result = knomee_identity::entry(program_id, &infos, data);
+
+        if result.is_ok() {
+            for key in keys {
+                let info = infos.iter().find(|info| *info.key == key).unwrap();
+                if info.executable {
+                    continue;
+                }
+                if info.lamports() == 0 {
+                    self.accounts.remove(&key);
+                } else {
+                    self.accounts.insert(
+                        key,
+                        TestAccount {
+                            lamports: info.lamports(),
+                            owner: *info.owner,
+                            data: info.data.borrow().to_vec(),
+                            executable: false,
+                        },
+                    );
+                }
+            }
+        }
+        result
+    }
+
+    /// Lay out accounts and instruction data the way the BPF loader does
+    fn serialize(
+        &self,
+        instruction: &Instruction,
+        flags: &HashMap<Pubkey, (bool, bool)>,
+    ) -> Vec<u64> {
+        let mut bytes: Vec<u8> = Vec::new();
+        bytes.extend_from_slice(&(instruction.accounts.len() as u64).to_le_bytes());
+
+        for (position, meta) in instruction.accounts.iter().enumerate() {
+            // A repeated account refers back to the position of its first occurrence
+            let first = instruction.accounts[..position]
+                .iter()
+                .position(|earlier| earlier.pubkey == meta.pubkey);
+            if let Some(index) = first {
+                bytes.push(index as u8);
+                bytes.extend_from_slice(&[0u8; 7]);
+                continue;
+            }
+
+            let account = self
+                .accounts
+                .get(&meta.pubkey)
+                .cloned()
+                .unwrap_or(TestAccount {
+                    lamports: 0,
+                    owner: system_program::ID,
+                    data: vec![],
+                    executable: false,
+                });
+            let (is_signer, is_writable) = flags[&meta.pubkey];
+
+            bytes.push(u8::MAX);
+            bytes.push(is_signer as u8);
+            bytes.push(is_writable as u8);
+            bytes.push(account.executable as u8);
+            bytes.extend_from_slice(&[0u8; 4]);
+            bytes.extend_from_slice(meta.pubkey.as_ref());
+            bytes.extend_from_slice(account.owner.as_ref());
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&account.data);
+            bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            bytes.resize(bytes.len().next_multiple_of(8), 0);
+            bytes.extend_from_slice(&0u64.to_le_bytes()); // rent_epoch
+        }
+
+        bytes.extend_from_slice(&(instruction.data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&instruction.data);
+        bytes.extend_from_slice(instruction.program_id.as_ref());
+
+        // u64 backing keeps the buffer 8-byte aligned, as the loader guarantees
+        let mut input = vec![0u64; bytes.len().div_ceil(8)];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                input.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+        }
+        input
+    }
+}
+
+// ============================================================
+// PROTOCOL FIXTURES
+// ============================================================
+
+pub fn governance_pda() -> Pubkey {
+    pda(&[GOVERNANCE_SEED])
+}
+
+pub fn identity_pda(owner: &Pubkey) -> Pubkey {
+    pda(&[IDENTITY_SEED, owner.as_ref()])
+}
+
+pub fn stake_account_pda(owner: &Pubkey) -> Pubkey {
+    pda(&[STAKE_ACCOUNT_SEED, owner.as_ref()])
+}
+
+pub fn stake_escrow_pda() -> Pubkey {
+    pda(&[STAKE_ESCROW_SEED])
+}
+
+pub fn treasury_vault_pda() -> Pubkey {
+    pda(&[TREASURY_VAULT_SEED])
+}
+
+pub fn tier_mint_pda(tier: IdentityTier) -> Pubkey {
+    pda(&[TIER_MINT_SEED, &[tier.to_u8()]])
+}
+
+pub fn token_authority_pda() -> Pubkey {
+    pda(&[IDENTITY_TOKEN_AUTHORITY_SEED])
+}
+
+pub fn claim_pda(claim_id: u64) -> Pubkey {
+    pda(&[
+        CLAIM_SEED,
+        &START_TIME.to_le_bytes(),
+        &claim_id.to_le_bytes(),
+    ])
+}
+
+pub fn vouch_pda(claim_id: u64, voucher: &Pubkey) -> Pubkey {
+    pda(&[VOUCH_SEED, &claim_id.to_le_bytes(), voucher.as_ref()])
+}
+
+impl Env {
+    /// Governance initialized at `START_TIME` with `params`, plus the stake escrow and treasury vault
+    pub fn with_governance(params: GovernanceParams) -> Self {
+        let mut env = Env::new();
+        let authority = env.payer;
+        env.put_anchor(
+            governance_pda(),
+            &Governance {
+                authority,
+                god_mode_authority: authority,
+                god_mode_active: true,
+                time_warp_seconds: 0,
+                params,
+                initialized_at: START_TIME,
+                bump: Pubkey::find_program_address(&[GOVERNANCE_SEED], &knomee_identity::ID).1,
+            },
+        );
+        let (escrow, vault) = (stake_escrow_pda(), treasury_vault_pda());
+        env.put_token_account(escrow, escrow, 0);
+        env.put_token_account(vault, governance_pda(), 0);
+        env
+    }
+
+    pub fn governance(&self) -> Governance {
+        self.get(&governance_pda())
+    }
+
+    /// Identity at `tier` for `owner` (verified at the current time unless GreyGhost)
+    pub fn put_identity(&mut self, owner: Pubkey, tier: IdentityTier) -> Pubkey {
+        let key = identity_pda(&owner);
+        let verified = !matches!(tier, IdentityTier::GreyGhost);
+        self.put_anchor(
+            key,
+            &Identity {
+                owner,
+                tier,
+                primary_address: owner,
+                verified_at: if verified { self.now() } else { 0 },
+                oracle_decay_start: if matches!(tier, IdentityTier::Oracle) {
+                    self.now()
+                } else {
+                    0
+                },
+                token_tier: IdentityTier::GreyGhost,
+                bump: Pubkey::find_program_address(
+                    &[IDENTITY_SEED, owner.as_ref()],
+                    &knomee_identity::ID,
+                )
+                .1,
+                ..Identity::default()
+            },
+        );
+        key
+    }
+
+    pub fn identity(&self, owner: &Pubkey) -> Identity {
+        self.get(&identity_pda(owner))
+    }
+
+    /// Stake account for `owner` with `deposited` KNOW (backed by the escrow) and `bonded` of it bonded
+    pub fn put_stake(&mut self, owner: Pubkey, deposited: u64, bonded: u64) -> Pubkey {
+        let key = stake_account_pda(&owner);
+        self.put_anchor(
+            key,
+            &StakeAccount {
+                owner,
+                deposited,
+                bonded,
+                bump: Pubkey::find_program_address(
+                    &[STAKE_ACCOUNT_SEED, owner.as_ref()],
+                    &knomee_identity::ID,
+                )
+                .1,
+                ..StakeAccount::default()
+            },
+        );
+        let escrow = stake_escrow_pda();
+        let balance = self.token_balance(&escrow);
+        self.put_token_account(escrow, escrow, balance + deposited);
+        key
+    }
+
+    pub fn stake(&self, owner: &Pubkey) -> StakeAccount {
+        self.get(&stake_account_pda(owner))
+    }
+
+    /// Tier token accounts for an identity holding no token yet, whose tier mints are not created
+    pub fn tier_token_accounts(
+        &self,
+        new_tier: IdentityTier,
+    ) -> knomee_identity::accounts::TierTokenAccounts {
+        knomee_identity::accounts::TierTokenAccounts {
+            token_authority: token_authority_pda(),
+            old_tier_mint: None,
+            old_token_account: None,
+            new_tier_mint: new_tier
+                .has_identity_token()
+                .then(|| tier_mint_pda(new_tier)),
+            new_token_account: None,
+            token_program: spl_token_2022::ID,
+        }
+    }
+}
+
+impl Env {
+    /// Active NewPrimary claim by `subject`, opened now and expiring after a day
+    pub fn put_claim(&mut self, claim_id: u64, subject: Pubkey) -> Pubkey {
+        let key = claim_pda(claim_id);
+        let claim = IdentityClaim {
+            claim_id,
+            claim_type: ClaimType::NewPrimary,
+            status: ClaimStatus::Active,
+            subject,
+            creator: subject,
+            created_at: self.now(),
+            expires_at: self.now() + 86_400,
+            created_slot: self.clock.slot,
+            bump: Pubkey::find_program_address(
+                &[
+                    CLAIM_SEED,
+                    &START_TIME.to_le_bytes(),
+                    &claim_id.to_le_bytes(),
+                ],
+                &knomee_identity::ID,
+            )
+            .1,
+            ..IdentityClaim::default()
+        };
+        self.put_anchor(key, &claim);
+        key
+    }
+
+    /// Mark claim `claim_id` resolved now with `status`, snapshotting its slash terms
+    pub fn decide_claim(&mut self, claim_id: u64, status: ClaimStatus) {
+        let params = self.governance().params;
+        let mut claim = self.claim(claim_id);
+        claim.status = status;
+        claim.resolved_at = self.now();
+        claim.snapshot_slash_terms(&params);
+        self.put_anchor(claim_pda(claim_id), &claim);
+    }
+
+    pub fn claim(&self, claim_id: u64) -> IdentityClaim {
+        self.get(&claim_pda(claim_id))
+    }
+
+    pub fn vouch(&self, claim_id: u64, voucher: &Pubkey) -> Vouch {
+        self.get(&vouch_pda(claim_id, voucher))
+    }
+
+    /// Cast a vouch FOR (`supports`) or AGAINST a claim, without a jury or delegations
+    pub fn cast_vouch(
+        &mut self,
+        claim_id: u64,
+        voucher: Pubkey,
+        supports: bool,
+        stake_amount: u64,
+    ) -> std::result::Result<(), ProgramError> {
+        let claim = self.claim(claim_id);
+        if supports {
+            self.run(
+                knomee_identity::accounts::VouchFor {
+                    governance: governance_pda(),
+                    claim: claim_pda(claim_id),
+                    voucher_identity: identity_pda(&voucher),
+                    subject_identity: identity_pda(&claim.subject),
+                    vouch: vouch_pda(claim_id, &voucher),
+                    jury: None,
+                    stake_account: stake_account_pda(&voucher),
+                    voucher,
+                    system_program: system_program::ID,
+                },
+                knomee_identity::instruction::VouchFor {
+                    claim_id,
+                    stake_amount,
+                },
+            )
+        } else {
+            self.run(
+                knomee_identity::accounts::VouchAgainst {
+                    governance: governance_pda(),
+                    claim: claim_pda(claim_id),
+                    voucher_identity: identity_pda(&voucher),
+                    vouch: vouch_pda(claim_id, &voucher),
+                    jury: None,
+                    stake_account: stake_account_pda(&voucher),
+                    voucher,
+                    system_program: system_program::ID,
+                },
+                knomee_identity::instruction::VouchAgainst {
+                    claim_id,
+                    stake_amount,
+                },
+            )
+        }
+    }
+
+    /// A funded wallet with an identity at `tier` and `deposited` KNOW staked
+    pub fn new_voter(&mut self, tier: IdentityTier, deposited: u64) -> Pubkey {
+        let voter = self.new_wallet();
+        self.put_identity(voter, tier);
+        self.put_stake(voter, deposited, 0);
+        voter
+    }
+}
+
+pub fn default_params() -> GovernanceParams {
+    GovernanceParams::default()
+}
+
+pub fn assert_error(result: std::result::Result<(), ProgramError>, error: KnomeeError) {
+    assert_eq!(result, Err(knomee_error(error)));
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use anchor_lang::{prelude::*, Discriminator};
+use common::*;
+use knomee_identity::{accounts, constants::*, events::EvidenceSubmitted, instruction, state::*};
+
+#[test]
+fn submitted_evidence_is_emitted_as_an_event() {
+    let mut env = Env::with_governance(default_params());
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(1, subject);
+    env.take_events();
+
+    let evidence = pda(&[EVIDENCE_SEED, &1u64.to_le_bytes(), &0u32.to_le_bytes()]);
+    env.run(
+        accounts::SubmitEvidence {
+            governance: governance_pda(),
+            claim: claim_pda(1),
+            evidence,
+            vouch: None,
+            submitter: subject,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::SubmitEvidence {
+            claim_id: 1,
+            content_hash: [9; 32],
+            uri: "ipfs://evidence".to_string(),
+            media_type: "image/png".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(env.claim(1).evidence_count, 1);
+
+    let events = env.take_events();
+    assert_eq!(events.len(), 1);
+    let (discriminator, data) = events[0].split_at(8);
+    assert_eq!(discriminator, EvidenceSubmitted::DISCRIMINATOR);
+    let event = EvidenceSubmitted::try_from_slice(data).unwrap();
+    assert_eq!((event.claim_id, event.index), (1, 0));
+    assert_eq!(event.submitter, subject);
+    assert_eq!(event.content_hash, [9; 32]);
+    assert_eq!(event.uri, "ipfs://evidence");
+    assert_eq!(event.media_type, "image/png");
+    assert_eq!(event.timestamp, env.now());
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use common::*;
+use knomee_identity::{accounts, errors::KnomeeError, instruction, state::GovernanceParams};
+
+fn update(
+    env: &mut Env,
+    params: GovernanceParams,
+) -> Result<(), anchor_lang::prelude::ProgramError> {
+    let authority = env.payer;
+    env.run(
+        accounts::UpdateGovernance {
+            governance: governance_pda(),
+            authority,
+        },
+        instruction::UpdateGovernanceParams { params },
+    )
+}
+
+#[test]
+fn update_rejects_invalid_appeal_and_recovery_params() {
+    let mut env = Env::with_governance(default_params());
+
+    let mut params = default_params();
+    params.appeal_slash_bps = 10_001;
+    assert_error(update(&mut env, params), KnomeeError::InvalidSlashRate);
+
+    let mut params = default_params();
+    params.appeal_stake_multiplier = 0;
+    assert_error(
+        update(&mut env, params),
+        KnomeeError::InvalidStakeMultiplier,
+    );
+
+    let mut params = default_params();
+    params.recovery_stake_multiplier = 0;
+    assert_error(
+        update(&mut env, params),
+        KnomeeError::InvalidStakeMultiplier,
+    );
+
+    let mut params = default_params();
+    params.appeal_slash_bps = 2_500;
+    update(&mut env, params).unwrap();
+    assert_eq!(env.governance().params.appeal_slash_bps, 2_500);
+}
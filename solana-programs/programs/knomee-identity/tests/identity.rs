@@ -0,0 +1,207 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use knomee_identity::{accounts, constants::*, errors::KnomeeError, instruction, state::*};
+
+fn migrate(
+    env: &mut Env,
+    owner: Pubkey,
+    new_owner: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    let tier = env.identity(&owner).tier;
+    let tier_token = env.tier_token_accounts(tier);
+    env.run(
+        accounts::MigrateIdentity {
+            governance: governance_pda(),
+            old_identity: identity_pda(&owner),
+            new_identity: identity_pda(&new_owner),
+            migration: pda(&[MIGRATION_SEED, owner.as_ref()]),
+            owner,
+            new_owner,
+            stake_account: stake_account_pda(&owner),
+            system_program: anchor_lang::system_program::ID,
+            tier_token,
+        },
+        instruction::MigrateIdentity {},
+    )
+}
+
+fn resolve(env: &mut Env, claim_id: u64) -> std::result::Result<(), ProgramError> {
+    let claim = env.claim(claim_id);
+    let tier = env.identity(&claim.subject).tier;
+    let tier_token = env.tier_token_accounts(tier);
+    env.run(
+        accounts::ResolveConsensus {
+            governance: governance_pda(),
+            claim: claim_pda(claim_id),
+            subject_identity: identity_pda(&claim.subject),
+            related_identity: None,
+            appealed_claim: None,
+            creator_identity: None,
+            treasury_vault: None,
+            resolver_token_account: None,
+            token_program: None,
+            tier_token,
+        },
+        instruction::ResolveConsensus { claim_id },
+    )
+}
+
+#[test]
+fn migration_waits_for_claims_about_the_old_key() {
+    let mut env = Env::with_governance(default_params());
+    let params = env.governance().params;
+    let owner = env.new_wallet();
+    env.put_identity(owner, IdentityTier::GreyGhost);
+    let owner_tokens = env.new_token_account(owner, 100 * KNOW);
+    let new_owner = env.new_wallet();
+    env.put_identity(new_owner, IdentityTier::GreyGhost);
+
+    let claim_id = env.now() as u64;
+    env.run(
+        accounts::RequestPrimaryVerification {
+            governance: governance_pda(),
+            claim: claim_pda(claim_id),
+            subject_identity: identity_pda(&owner),
+            subject: owner,
+            subject_token_account: owner_tokens,
+            stake_escrow: stake_escrow_pda(),
+            treasury_vault: None,
+            token_program: anchor_spl::token::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::RequestPrimaryVerification {
+            justification: "me".to_string(),
+            stake_amount: params.min_stake_lamports * params.primary_stake_multiplier as u64,
+        },
+    )
+    .unwrap();
+    assert_eq!(env.identity(&owner).open_claims, 1);
+    assert_error(
+        migrate(&mut env, owner, new_owner),
+        KnomeeError::IdentityHasOpenClaims,
+    );
+
+    // Without votes the claim is rejected, and can still be appealed for the appeal window
+    env.warp(60, 1);
+    resolve(&mut env, claim_id).unwrap();
+    assert_eq!(env.identity(&owner).open_claims, 0);
+    assert_error(
+        migrate(&mut env, owner, new_owner),
+        KnomeeError::IdentityHasOpenClaims,
+    );
+
+    env.warp(params.appeal_window + 1, 1);
+    migrate(&mut env, owner, new_owner).unwrap();
+    assert!(!env.exists(&identity_pda(&owner)));
+}
+
+#[test]
+fn migration_ends_delegation() {
+    let mut env = Env::with_governance(default_params());
+    let owner = env.new_wallet();
+    let new_owner = env.new_wallet();
+    env.put_identity(new_owner, IdentityTier::GreyGhost);
+    env.put_identity(owner, IdentityTier::PrimaryID);
+    let mut identity = env.identity(&owner);
+    identity.delegated_to = Pubkey::new_unique();
+    env.put_anchor(identity_pda(&owner), &identity);
+
+    env.warp(60, 1);
+    migrate(&mut env, owner, new_owner).unwrap();
+
+    let migrated = env.identity(&new_owner);
+    assert!(migrated.tier == IdentityTier::PrimaryID);
+    assert_eq!(migrated.delegated_to, Pubkey::default());
+    assert_eq!(migrated.delegation_ended_at, env.now());
+}
+
+/// Guardian recovery of `owner` to `new_owner`, approved and past its veto window
+fn approved_guardian_recovery(env: &mut Env, owner: Pubkey, new_owner: Pubkey) {
+    env.put_anchor(
+        pda(&[RECOVERY_CONFIG_SEED, owner.as_ref()]),
+        &RecoveryConfig {
+            owner,
+            guardians: vec![Pubkey::new_unique()],
+            threshold: 1,
+            veto_delay: MIN_GUARDIAN_VETO_DELAY,
+            proposed_owner: new_owner,
+            proposed_at: env.now(),
+            approvals: 1,
+            approved_at: env.now(),
+            bump: Pubkey::find_program_address(
+                &[RECOVERY_CONFIG_SEED, owner.as_ref()],
+                &knomee_identity::ID,
+            )
+            .1,
+        },
+    );
+    env.warp(MIN_GUARDIAN_VETO_DELAY, 1);
+}
+
+fn execute_guardian_recovery(
+    env: &mut Env,
+    owner: Pubkey,
+    new_owner: Pubkey,
+    with_new_stake_account: bool,
+    new_tier: IdentityTier,
+) -> std::result::Result<(), ProgramError> {
+    let tier_token = env.tier_token_accounts(new_tier);
+    env.run(
+        accounts::ExecuteGuardianRecovery {
+            governance: governance_pda(),
+            recovery_config: pda(&[RECOVERY_CONFIG_SEED, owner.as_ref()]),
+            old_identity: identity_pda(&owner),
+            new_identity: identity_pda(&new_owner),
+            migration: pda(&[MIGRATION_SEED, owner.as_ref()]),
+            stake_account: stake_account_pda(&owner),
+            new_stake_account: with_new_stake_account.then(|| stake_account_pda(&new_owner)),
+            new_owner,
+            system_program: anchor_lang::system_program::ID,
+            tier_token,
+        },
+        instruction::ExecuteGuardianRecovery {},
+    )
+}
+
+#[test]
+fn guardian_recovery_moves_the_oracle_bond() {
+    let mut env = Env::with_governance(default_params());
+    let min_bond = env.governance().params.min_oracle_bond;
+    let owner = env.new_wallet();
+    env.put_identity(owner, IdentityTier::Oracle);
+    env.put_stake(owner, min_bond + 5 * KNOW, min_bond);
+    let new_owner = env.new_wallet();
+    env.put_identity(new_owner, IdentityTier::GreyGhost);
+    env.put_stake(new_owner, 0, 0);
+    approved_guardian_recovery(&mut env, owner, new_owner);
+
+    // The bond needs somewhere to go
+    assert_error(
+        execute_guardian_recovery(&mut env, owner, new_owner, false, IdentityTier::Oracle),
+        KnomeeError::StakeAccountRequired,
+    );
+    execute_guardian_recovery(&mut env, owner, new_owner, true, IdentityTier::Oracle).unwrap();
+
+    assert!(env.identity(&new_owner).tier == IdentityTier::Oracle);
+    let (lost, recovered) = (env.stake(&owner), env.stake(&new_owner));
+    assert_eq!((lost.deposited, lost.bonded), (5 * KNOW, 0));
+    assert_eq!(
+        (recovered.deposited, recovered.bonded),
+        (min_bond, min_bond)
+    );
+}
+
+#[test]
+fn guardian_recovery_demotes_an_unbonded_oracle() {
+    let mut env = Env::with_governance(default_params());
+    let owner = env.new_wallet();
+    env.put_identity(owner, IdentityTier::Oracle);
+    let new_owner = env.new_wallet();
+    env.put_identity(new_owner, IdentityTier::GreyGhost);
+    approved_guardian_recovery(&mut env, owner, new_owner);
+
+    execute_guardian_recovery(&mut env, owner, new_owner, false, IdentityTier::PrimaryID).unwrap();
+    assert!(env.identity(&new_owner).tier == IdentityTier::PrimaryID);
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use knomee_identity::{accounts, instruction, state::IdentityTier};
+
+fn initialize_tier_mint(
+    env: &mut Env,
+    tier: IdentityTier,
+) -> std::result::Result<(), ProgramError> {
+    let authority = env.payer;
+    env.run(
+        accounts::InitializeTierMint {
+            governance: governance_pda(),
+            tier_mint: tier_mint_pda(tier),
+            token_authority: token_authority_pda(),
+            authority,
+            token_program: spl_token_2022::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::InitializeTierMint { tier },
+    )
+}
+
+#[test]
+fn tier_mint_created_at_empty_pda() {
+    let mut env = Env::with_governance(default_params());
+    initialize_tier_mint(&mut env, IdentityTier::PrimaryID).unwrap();
+
+    let mint = &env.accounts[&tier_mint_pda(IdentityTier::PrimaryID)];
+    assert_eq!(mint.owner, spl_token_2022::ID);
+    assert_eq!(
+        mint.lamports,
+        Rent::default().minimum_balance(mint.data.len())
+    );
+}
+
+#[test]
+fn tier_mint_created_at_prefunded_pda() {
+    let mut env = Env::with_governance(default_params());
+    let key = tier_mint_pda(IdentityTier::Oracle);
+
+    // A griefer sends lamports to the PDA before governance creates the mint
+    env.fund(key, 1);
+    initialize_tier_mint(&mut env, IdentityTier::Oracle).unwrap();
+
+    let mint = &env.accounts[&key];
+    assert_eq!(mint.owner, spl_token_2022::ID);
+    assert!(!mint.data.is_empty());
+    assert_eq!(
+        mint.lamports,
+        Rent::default().minimum_balance(mint.data.len())
+    );
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use common::*;
+use knomee_identity::{accounts, constants::*, errors::KnomeeError, instruction, state::*};
+
+fn jury_pda(claim_id: u64) -> Pubkey {
+    pda(&[JURY_SEED, &claim_id.to_le_bytes()])
+}
+
+/// Active claim drawing a jury of half the voters, created at the current slot
+fn jury_claim(env: &mut Env, claim_id: u64) {
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(claim_id, subject);
+    let mut claim = env.claim(claim_id);
+    claim.jury_selection_bps = 5_000;
+    env.put_anchor(claim_pda(claim_id), &claim);
+}
+
+fn draw_jury(env: &mut Env, claim_id: u64) -> std::result::Result<(), ProgramError> {
+    let payer = env.payer;
+    env.run(
+        accounts::DrawJury {
+            governance: governance_pda(),
+            claim: claim_pda(claim_id),
+            jury: jury_pda(claim_id),
+            recent_slothashes: slot_hashes::ID,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::DrawJury { claim_id },
+    )
+}
+
+fn reanchor_jury_draw(env: &mut Env, claim_id: u64) -> std::result::Result<(), ProgramError> {
+    env.run(
+        accounts::ReanchorJuryDraw {
+            claim: claim_pda(claim_id),
+            jury: jury_pda(claim_id),
+            recent_slothashes: slot_hashes::ID,
+        },
+        instruction::ReanchorJuryDraw { claim_id },
+    )
+}
+
+#[test]
+fn jury_is_drawn_from_the_exact_target_slot() {
+    let mut env = Env::with_governance(default_params());
+    jury_claim(&mut env, 1);
+    let target = START_SLOT + JURY_DRAW_DELAY_SLOTS;
+
+    // Not reached yet: neither a draw nor a re-anchor
+    env.put_slot_hashes(&[(START_SLOT, [1; 32])]);
+    assert_error(draw_jury(&mut env, 1), KnomeeError::JurySlotUnavailable);
+    assert_error(
+        reanchor_jury_draw(&mut env, 1),
+        KnomeeError::JurySlotAvailable,
+    );
+
+    env.warp(10, JURY_DRAW_DELAY_SLOTS + 1);
+    env.put_slot_hashes(&[(target + 1, [3; 32]), (target, [2; 32])]);
+    assert_error(
+        reanchor_jury_draw(&mut env, 1),
+        KnomeeError::JurySlotAvailable,
+    );
+    draw_jury(&mut env, 1).unwrap();
+
+    let jury: Jury = env.get(&jury_pda(1));
+    assert_eq!(jury.source_slot, target);
+    assert_eq!(jury.selection_bps, 5_000);
+    assert_error(
+        reanchor_jury_draw(&mut env, 1),
+        KnomeeError::JuryAlreadyDrawn,
+    );
+}
+
+#[test]
+fn skipped_target_slot_reanchors_the_draw() {
+    let mut env = Env::with_governance(default_params());
+    jury_claim(&mut env, 1);
+    let target = START_SLOT + JURY_DRAW_DELAY_SLOTS;
+
+    // The target slot was skipped; the next slot's hash is not used instead
+    env.warp(10, JURY_DRAW_DELAY_SLOTS + 2);
+    env.put_slot_hashes(&[(target + 2, [3; 32]), (target + 1, [2; 32])]);
+    assert_error(draw_jury(&mut env, 1), KnomeeError::JurySlotUnavailable);
+
+    reanchor_jury_draw(&mut env, 1).unwrap();
+    let current_slot = target + 2;
+    assert_eq!(env.claim(1).created_slot, current_slot);
+    assert_error(draw_jury(&mut env, 1), KnomeeError::JurySlotUnavailable);
+
+    let new_target = current_slot + JURY_DRAW_DELAY_SLOTS;
+    env.warp(10, JURY_DRAW_DELAY_SLOTS);
+    env.put_slot_hashes(&[(new_target, [4; 32]), (target + 2, [3; 32])]);
+    draw_jury(&mut env, 1).unwrap();
+    assert_eq!(env.get::<Jury>(&jury_pda(1)).source_slot, new_target);
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use knomee_identity::{accounts, instruction, state::*};
+
+const BOUNTY_FUNDS: u64 = 10 * KNOW;
+
+/// Resolve claim `claim_id`, passing the bounty accounts for `resolver_tokens`
+fn resolve_for_bounty(
+    env: &mut Env,
+    claim_id: u64,
+    resolver_tokens: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    let claim = env.claim(claim_id);
+    env.run(
+        accounts::ResolveConsensus {
+            governance: governance_pda(),
+            claim: claim_pda(claim_id),
+            subject_identity: identity_pda(&claim.subject),
+            related_identity: None,
+            appealed_claim: None,
+            creator_identity: None,
+            treasury_vault: Some(treasury_vault_pda()),
+            resolver_token_account: Some(resolver_tokens),
+            token_program: Some(anchor_spl::token::ID),
+            tier_token: env.tier_token_accounts(IdentityTier::PrimaryID),
+        },
+        instruction::ResolveConsensus { claim_id },
+    )
+}
+
+/// Claim `claim_id` opened now, with `votes` minimum-stake vouches against it
+fn opposed_claim(env: &mut Env, claim_id: u64, votes: usize) {
+    let min_stake = env.governance().params.min_stake_lamports;
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(claim_id, subject);
+    for _ in 0..votes {
+        let voter = env.new_voter(IdentityTier::PrimaryID, 10 * KNOW);
+        env.cast_vouch(claim_id, voter, false, min_stake).unwrap();
+    }
+}
+
+fn bounty_env() -> (Env, Pubkey) {
+    let mut env = Env::with_governance(default_params());
+    env.put_token_account(treasury_vault_pda(), governance_pda(), BOUNTY_FUNDS);
+    let resolver = env.new_wallet();
+    let resolver_tokens = env.new_token_account(resolver, 0);
+    (env, resolver_tokens)
+}
+
+#[test]
+fn early_resolution_needs_minimum_votes_for_the_bounty() {
+    let (mut env, resolver_tokens) = bounty_env();
+    let params = env.governance().params;
+    assert_eq!(params.resolution_bounty_min_votes, 3);
+
+    // A lone vote rejects the claim, but resolving it earns nothing
+    opposed_claim(&mut env, 1, 1);
+    resolve_for_bounty(&mut env, 1, resolver_tokens).unwrap();
+    assert!(env.claim(1).status == ClaimStatus::Rejected);
+    assert_eq!(env.token_balance(&resolver_tokens), 0);
+
+    opposed_claim(&mut env, 2, 3);
+    resolve_for_bounty(&mut env, 2, resolver_tokens).unwrap();
+    assert_eq!(
+        env.token_balance(&resolver_tokens),
+        params.resolution_bounty_lamports
+    );
+    assert_eq!(
+        env.token_balance(&treasury_vault_pda()),
+        BOUNTY_FUNDS - params.resolution_bounty_lamports
+    );
+}
+
+#[test]
+fn expired_claims_earn_the_bounty() {
+    let (mut env, resolver_tokens) = bounty_env();
+    opposed_claim(&mut env, 1, 0);
+    env.warp(86_400, 1);
+
+    resolve_for_bounty(&mut env, 1, resolver_tokens).unwrap();
+    assert!(env.claim(1).status == ClaimStatus::Expired);
+    assert_eq!(
+        env.token_balance(&resolver_tokens),
+        env.governance().params.resolution_bounty_lamports
+    );
+}
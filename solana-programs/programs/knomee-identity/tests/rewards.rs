@@ -0,0 +1,176 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use knomee_identity::{accounts, constants::*, errors::KnomeeError, instruction, state::*};
+
+/// Rewards pool launched now, with `funded` KNOW in its vault
+fn put_rewards_pool(env: &mut Env, funded: u64) {
+    let vault = pda(&[REWARDS_VAULT_SEED]);
+    let rewards_pool = pda(&[REWARDS_POOL_SEED]);
+    env.put_anchor(
+        rewards_pool,
+        &RewardsPool {
+            know_mint: env.know_mint,
+            vault,
+            launched_at: env.now(),
+            total_funded: funded,
+            bump: Pubkey::find_program_address(&[REWARDS_POOL_SEED], &knomee_identity::ID).1,
+            ..RewardsPool::default()
+        },
+    );
+    env.put_token_account(vault, rewards_pool, funded);
+}
+
+fn claim_voting_reward(
+    env: &mut Env,
+    claim_id: u64,
+    voucher: Pubkey,
+    voucher_token_account: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    env.run(
+        accounts::ClaimVotingReward {
+            governance: governance_pda(),
+            rewards_pool: pda(&[REWARDS_POOL_SEED]),
+            rewards_vault: pda(&[REWARDS_VAULT_SEED]),
+            claim: claim_pda(claim_id),
+            vouch: vouch_pda(claim_id, &voucher),
+            voucher,
+            voucher_token_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ClaimVotingReward { claim_id },
+    )
+}
+
+#[test]
+fn voting_reward_is_fixed_and_uses_the_tier_at_vote_time() {
+    let mut env = Env::with_governance(default_params());
+    let params = env.governance().params;
+    put_rewards_pool(&mut env, 1_000 * KNOW);
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(1, subject);
+
+    let oracle = env.new_voter(IdentityTier::Oracle, 0);
+    env.put_stake(
+        oracle,
+        params.min_oracle_bond + 50 * KNOW,
+        params.min_oracle_bond,
+    );
+    env.cast_vouch(1, oracle, true, 50 * KNOW).unwrap();
+    let loser = env.new_voter(IdentityTier::PrimaryID, 10 * KNOW);
+    env.cast_vouch(1, loser, false, params.min_stake_lamports)
+        .unwrap();
+    assert!(env.vouch(1, &oracle).voted_as_oracle);
+
+    // Losing Oracle status after voting keeps the Oracle reward for this vote
+    env.put_identity(oracle, IdentityTier::PrimaryID);
+    env.decide_claim(1, ClaimStatus::Approved);
+    let oracle_tokens = env.new_token_account(oracle, 0);
+    let loser_tokens = env.new_token_account(loser, 0);
+
+    assert_error(
+        claim_voting_reward(&mut env, 1, oracle, oracle_tokens),
+        KnomeeError::ClaimNotFinal,
+    );
+    env.warp(params.appeal_window + 1, 1);
+    assert_error(
+        claim_voting_reward(&mut env, 1, loser, loser_tokens),
+        KnomeeError::NoVotingReward,
+    );
+    claim_voting_reward(&mut env, 1, oracle, oracle_tokens).unwrap();
+
+    // Independent of the 50 KNOW stake, and doubled for early adopters
+    let expected =
+        (params.voting_reward + ORACLE_REWARD_PER_CLAIM) * EARLY_ADOPTER_MULTIPLIER as u64;
+    assert_eq!(env.token_balance(&oracle_tokens), expected);
+    assert!(env.vouch(1, &oracle).know_reward_paid);
+    assert_error(
+        claim_voting_reward(&mut env, 1, oracle, oracle_tokens),
+        KnomeeError::RewardsAlreadyClaimed,
+    );
+}
+
+#[test]
+fn close_vouch_requires_the_voting_reward_to_be_claimed() {
+    let mut env = Env::with_governance(default_params());
+    let params = env.governance().params;
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(1, subject);
+    let voter = env.new_voter(IdentityTier::PrimaryID, 10 * KNOW);
+    env.cast_vouch(1, voter, true, params.min_stake_lamports)
+        .unwrap();
+
+    env.decide_claim(1, ClaimStatus::Approved);
+    let mut claim = env.claim(1);
+    claim.settled_vouch_count = claim.vouch_count;
+    env.put_anchor(claim_pda(1), &claim);
+    let mut vouch = env.vouch(1, &voter);
+    vouch.rewards_claimed = true;
+    env.put_anchor(vouch_pda(1, &voter), &vouch);
+    env.warp(params.appeal_window + 1, 1);
+
+    let close = || {
+        (
+            accounts::CloseVouch {
+                claim: claim_pda(1),
+                vouch: vouch_pda(1, &voter),
+                governance: governance_pda(),
+                rewards_pool: pda(&[REWARDS_POOL_SEED]),
+                voucher: voter,
+            },
+            instruction::CloseVouch { claim_id: 1 },
+        )
+    };
+    put_rewards_pool(&mut env, 1_000 * KNOW);
+    let (close_accounts, data) = close();
+    assert_error(
+        env.run(close_accounts, data),
+        KnomeeError::VotingRewardNotClaimed,
+    );
+
+    let voter_tokens = env.new_token_account(voter, 0);
+    claim_voting_reward(&mut env, 1, voter, voter_tokens).unwrap();
+    let (close_accounts, data) = close();
+    env.run(close_accounts, data).unwrap();
+    assert!(!env.exists(&vouch_pda(1, &voter)));
+    assert_eq!(env.claim(1).closed_vouch_count, 1);
+}
+
+#[test]
+fn primary_reward_waits_for_the_appeal_window() {
+    let mut env = Env::with_governance(default_params());
+    let params = env.governance().params;
+    put_rewards_pool(&mut env, 1_000 * KNOW);
+    let owner = env.new_wallet();
+    env.put_identity(owner, IdentityTier::PrimaryID);
+    let mut identity = env.identity(&owner);
+    identity.last_claim_resolved_at = env.now();
+    env.put_anchor(identity_pda(&owner), &identity);
+    let owner_tokens = env.new_token_account(owner, 0);
+
+    let claim_reward = || accounts::ClaimPrimaryReward {
+        governance: governance_pda(),
+        rewards_pool: pda(&[REWARDS_POOL_SEED]),
+        rewards_vault: pda(&[REWARDS_VAULT_SEED]),
+        identity: identity_pda(&owner),
+        owner,
+        owner_token_account: owner_tokens,
+        token_program: anchor_spl::token::ID,
+    };
+    assert_error(
+        env.run(claim_reward(), instruction::ClaimPrimaryReward {}),
+        KnomeeError::ClaimNotFinal,
+    );
+
+    env.warp(params.appeal_window + 1, 1);
+    env.run(claim_reward(), instruction::ClaimPrimaryReward {})
+        .unwrap();
+    assert_eq!(
+        env.token_balance(&owner_tokens),
+        PRIMARY_ID_REWARD * EARLY_ADOPTER_MULTIPLIER as u64
+    );
+    assert!(env.identity(&owner).primary_reward_claimed);
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use knomee_identity::{accounts, errors::KnomeeError, instruction, state::*};
+
+/// Claim by `subject` whose creator staked `stake` KNOW into the escrow
+fn put_staked_claim(env: &mut Env, claim_id: u64, subject: Pubkey, stake: u64) {
+    env.put_claim(claim_id, subject);
+    let mut claim = env.claim(claim_id);
+    claim.creator_stake = stake;
+    claim.total_stake = stake;
+    env.put_anchor(claim_pda(claim_id), &claim);
+    let escrow = stake_escrow_pda();
+    let balance = env.token_balance(&escrow);
+    env.put_token_account(escrow, escrow, balance + stake);
+}
+
+fn settle_creator_stake(
+    env: &mut Env,
+    claim_id: u64,
+    creator_token_account: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    env.run(
+        accounts::SettleCreatorStake {
+            governance: governance_pda(),
+            claim: claim_pda(claim_id),
+            creator_token_account,
+            stake_escrow: stake_escrow_pda(),
+            treasury_vault: treasury_vault_pda(),
+            know_mint: env.know_mint,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::SettleCreatorStake { claim_id },
+    )
+}
+
+#[test]
+fn approved_claim_refunds_the_creator_stake() {
+    let mut env = Env::with_governance(default_params());
+    let params = env.governance().params;
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    put_staked_claim(&mut env, 1, subject, 30 * KNOW);
+    let subject_tokens = env.new_token_account(subject, 0);
+    env.decide_claim(1, ClaimStatus::Approved);
+
+    let close_claim = || {
+        (
+            accounts::CloseClaim {
+                governance: governance_pda(),
+                claim: claim_pda(1),
+                creator: subject,
+            },
+            instruction::CloseClaim { claim_id: 1 },
+        )
+    };
+    assert_error(
+        settle_creator_stake(&mut env, 1, subject_tokens),
+        KnomeeError::ClaimNotFinal,
+    );
+    env.warp(params.appeal_window + 1, 1);
+    let (close_accounts, data) = close_claim();
+    assert_error(
+        env.run(close_accounts, data),
+        KnomeeError::CreatorStakeNotSettled,
+    );
+
+    settle_creator_stake(&mut env, 1, subject_tokens).unwrap();
+    assert_eq!(env.token_balance(&subject_tokens), 30 * KNOW);
+    assert_eq!(env.token_balance(&stake_escrow_pda()), 0);
+    assert_error(
+        settle_creator_stake(&mut env, 1, subject_tokens),
+        KnomeeError::RewardsAlreadyClaimed,
+    );
+
+    let (close_accounts, data) = close_claim();
+    env.run(close_accounts, data).unwrap();
+    assert!(!env.exists(&claim_pda(1)));
+}
+
+#[test]
+fn rejected_claim_splits_the_creator_stake() {
+    let mut env = Env::with_governance(default_params());
+    let params = env.governance().params;
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    put_staked_claim(&mut env, 1, subject, 100 * KNOW);
+    let voter = env.new_voter(IdentityTier::PrimaryID, 10 * KNOW);
+    env.cast_vouch(1, voter, false, params.min_stake_lamports)
+        .unwrap();
+    env.decide_claim(1, ClaimStatus::Rejected);
+    env.warp(params.appeal_window + 1, 1);
+
+    let subject_tokens = env.new_token_account(subject, 0);
+    settle_creator_stake(&mut env, 1, subject_tokens).unwrap();
+    assert_eq!(env.token_balance(&subject_tokens), 0);
+    assert_eq!(env.token_balance(&treasury_vault_pda()), 20 * KNOW);
+    assert_eq!(env.claim(1).total_slashed, 100 * KNOW);
+
+    // The winners' 70% stays in escrow and is credited to the winning vouch
+    env.run(
+        accounts::ClaimRewards {
+            governance: governance_pda(),
+            claim: claim_pda(1),
+            vouch: vouch_pda(1, &voter),
+            voucher_identity: identity_pda(&voter),
+            voucher: voter,
+            settler: voter,
+            stake_account: stake_account_pda(&voter),
+            stake_escrow: stake_escrow_pda(),
+            treasury_vault: treasury_vault_pda(),
+            know_mint: env.know_mint,
+            token_program: anchor_spl::token::ID,
+            tier_token: env.tier_token_accounts(IdentityTier::PrimaryID),
+        },
+        instruction::ClaimRewards { claim_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(env.stake(&voter).deposited, 80 * KNOW);
+    assert_eq!(env.token_balance(&stake_escrow_pda()), 80 * KNOW);
+}
+
+#[test]
+fn unopposed_rejection_sends_the_winners_share_to_the_treasury() {
+    let mut env = Env::with_governance(default_params());
+    let params = env.governance().params;
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    put_staked_claim(&mut env, 1, subject, 100 * KNOW);
+    env.decide_claim(1, ClaimStatus::Rejected);
+    env.warp(params.appeal_window + 1, 1);
+
+    let subject_tokens = env.new_token_account(subject, 0);
+    settle_creator_stake(&mut env, 1, subject_tokens).unwrap();
+    assert_eq!(env.token_balance(&treasury_vault_pda()), 90 * KNOW);
+    assert_eq!(env.token_balance(&stake_escrow_pda()), 0);
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use common::*;
+use knomee_identity::{accounts, errors::KnomeeError, instruction, state::*};
+
+#[test]
+fn deposit_unstake_and_withdraw() {
+    let mut env = Env::with_governance(default_params());
+    let owner = env.new_wallet();
+    let wallet_tokens = env.new_token_account(owner, 50 * KNOW);
+
+    env.run(
+        accounts::InitializeStakeAccount {
+            stake_account: stake_account_pda(&owner),
+            owner,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::InitializeStakeAccount {},
+    )
+    .unwrap();
+
+    env.run(
+        accounts::DepositStake {
+            stake_account: stake_account_pda(&owner),
+            owner,
+            owner_token_account: wallet_tokens,
+            stake_escrow: stake_escrow_pda(),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::DepositStake { amount: 20 * KNOW },
+    )
+    .unwrap();
+    assert_eq!(env.stake(&owner).deposited, 20 * KNOW);
+    assert_eq!(env.token_balance(&wallet_tokens), 30 * KNOW);
+    assert_eq!(env.token_balance(&stake_escrow_pda()), 20 * KNOW);
+
+    let unstake = |amount| {
+        (
+            accounts::RequestUnstake {
+                governance: governance_pda(),
+                stake_account: stake_account_pda(&owner),
+                owner,
+            },
+            instruction::RequestUnstake { amount },
+        )
+    };
+    let (unstake_accounts, data) = unstake(21 * KNOW);
+    assert_error(
+        env.run(unstake_accounts, data),
+        KnomeeError::InsufficientAvailableStake,
+    );
+    let (unstake_accounts, data) = unstake(5 * KNOW);
+    env.run(unstake_accounts, data).unwrap();
+
+    let withdraw = || accounts::WithdrawStake {
+        governance: governance_pda(),
+        stake_account: stake_account_pda(&owner),
+        owner,
+        owner_token_account: wallet_tokens,
+        stake_escrow: stake_escrow_pda(),
+        token_program: anchor_spl::token::ID,
+    };
+    assert_error(
+        env.run(withdraw(), instruction::WithdrawStake {}),
+        KnomeeError::StakeStillUnbonding,
+    );
+
+    env.warp(env.governance().params.stake_unbonding_period, 1);
+    env.run(withdraw(), instruction::WithdrawStake {}).unwrap();
+    assert_eq!(env.stake(&owner).deposited, 15 * KNOW);
+    assert_eq!(env.token_balance(&wallet_tokens), 35 * KNOW);
+}
+
+fn enforce_oracle_bond(
+    env: &mut Env,
+    oracle: Pubkey,
+    stake_owner: Pubkey,
+) -> std::result::Result<(), ProgramError> {
+    env.run(
+        accounts::EnforceOracleBond {
+            governance: governance_pda(),
+            identity: identity_pda(&oracle),
+            stake_account: stake_account_pda(&stake_owner),
+            tier_token: env.tier_token_accounts(IdentityTier::PrimaryID),
+        },
+        instruction::EnforceOracleBond {},
+    )
+}
+
+#[test]
+fn enforce_oracle_bond_reads_the_oracles_own_stake_account() {
+    let mut env = Env::with_governance(default_params());
+    let min_bond = env.governance().params.min_oracle_bond;
+    let oracle = env.new_wallet();
+    env.put_identity(oracle, IdentityTier::Oracle);
+    env.put_stake(oracle, min_bond, min_bond);
+    let unbonded = env.new_wallet();
+    env.put_stake(unbonded, 0, 0);
+
+    assert_error(
+        enforce_oracle_bond(&mut env, oracle, oracle),
+        KnomeeError::OracleBondSufficient,
+    );
+    assert_eq!(
+        enforce_oracle_bond(&mut env, oracle, unbonded),
+        Err(ProgramError::Custom(ErrorCode::ConstraintSeeds as u32))
+    );
+    assert!(env.identity(&oracle).tier == IdentityTier::Oracle);
+
+    // Raising the minimum leaves the Oracle under-bonded
+    let mut governance = env.governance();
+    governance.params.min_oracle_bond = min_bond + 1;
+    env.put_anchor(governance_pda(), &governance);
+    enforce_oracle_bond(&mut env, oracle, oracle).unwrap();
+    assert!(env.identity(&oracle).tier == IdentityTier::PrimaryID);
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::*;
+use knomee_identity::{errors::KnomeeError, state::*};
+
+fn share_capped_env() -> Env {
+    let mut params = default_params();
+    params.max_weight_share_bps = 2000;
+    params.min_stake_lamports = KNOW;
+    Env::with_governance(params)
+}
+
+#[test]
+fn weight_share_cap_clamps_locked_stake() {
+    let mut env = share_capped_env();
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(1, subject);
+
+    let voters: Vec<_> = (0..4)
+        .map(|_| env.new_voter(IdentityTier::PrimaryID, 10 * KNOW))
+        .collect();
+    for voter in &voters {
+        env.cast_vouch(1, *voter, true, KNOW).unwrap();
+    }
+
+    // Total weight is 4 KNOW; a 20% share allows one more vote of 1 KNOW
+    let whale = env.new_voter(IdentityTier::PrimaryID, 50 * KNOW);
+    env.cast_vouch(1, whale, false, 50 * KNOW).unwrap();
+
+    let vouch = env.vouch(1, &whale);
+    assert_eq!(vouch.stake, KNOW);
+    assert_eq!(vouch.counted_vote, KNOW as u128);
+    assert_eq!(env.stake(&whale).locked, KNOW);
+
+    let claim = env.claim(1);
+    assert_eq!(claim.stake_against, KNOW);
+    assert_eq!(claim.total_stake, 5 * KNOW);
+    assert_eq!(claim.total_votes_against, KNOW as u128);
+}
+
+#[test]
+fn opening_oracle_vouch_keeps_full_weight() {
+    let mut env = share_capped_env();
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(1, subject);
+
+    let oracle = env.new_wallet();
+    env.put_identity(oracle, IdentityTier::Oracle);
+    let bond = env.governance().params.min_oracle_bond;
+    env.put_stake(oracle, bond + 10 * KNOW, bond);
+
+    env.cast_vouch(1, oracle, true, 10 * KNOW).unwrap();
+
+    // The first vouch keeps its minimum-stake vote, weighted as an Oracle
+    let weight = env.governance().params.oracle_vote_weight as u128;
+    let vouch = env.vouch(1, &oracle);
+    assert_eq!(vouch.stake, KNOW);
+    assert_eq!(vouch.counted_vote, weight * KNOW as u128);
+    assert_eq!(env.claim(1).total_votes_for, weight * KNOW as u128);
+}
+
+#[test]
+fn under_bonded_oracle_cannot_vouch() {
+    let mut env = share_capped_env();
+    let subject = env.new_wallet();
+    env.put_identity(subject, IdentityTier::GreyGhost);
+    env.put_claim(1, subject);
+
+    let oracle = env.new_wallet();
+    env.put_identity(oracle, IdentityTier::Oracle);
+    let bond = env.governance().params.min_oracle_bond;
+    env.put_stake(oracle, bond - 1 + 10 * KNOW, bond - 1);
+
+    for supports in [true, false] {
+        assert_error(
+            env.cast_vouch(1, oracle, supports, KNOW),
+            KnomeeError::OracleBondTooLow,
+        );
+    }
+}